@@ -0,0 +1,154 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fmt;
+
+use number::ArithmeticError;
+use tokenizer::span::{Span, Spanned};
+use tokenizer::token::Token;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvalError {
+    UnexpectedCharacter(Spanned<char>),
+    UnexpectedToken(Spanned<Token>),
+    MismatchedParenthesis(Span),
+    MissingOperand(Span),
+    TrailingOperand(Span),
+    // An expression nested deeper than the parser allows, such as thousands
+    // of terms added one after the other.
+    TooDeep(Span),
+    InvalidLiteral(Spanned<String>),
+    MisplacedSeparator(Span),
+    Arithmetic(ArithmeticError, Span),
+    UnknownVariable(Spanned<String>),
+    UnknownFunction(Spanned<String>),
+    WrongArgumentCount { function: Spanned<String>, expected: usize, found: usize },
+    RecursionLimit(Span),
+    // Assigning to a constant such as `pi`, or naming a parameter after it.
+    ConstantAssignment(Spanned<String>),
+    // A boolean where a number is required, as in `1 + (2 < 3)`, or the
+    // other way around, as in `1 ? 2 : 3`.
+    ExpectedNumber(Span),
+    ExpectedBoolean(Span),
+    // A program ending with a function definition.
+    MissingValue(Span)
+}
+
+impl EvalError {
+    // Part of the expression the error points at.
+    pub fn span(&self) -> Span {
+        match *self {
+            EvalError::UnexpectedCharacter(ref character) => character.span,
+            EvalError::UnexpectedToken(ref token) => token.span,
+            EvalError::MismatchedParenthesis(span) => span,
+            EvalError::MissingOperand(span) => span,
+            EvalError::TrailingOperand(span) => span,
+            EvalError::TooDeep(span) => span,
+            EvalError::InvalidLiteral(ref literal) => literal.span,
+            EvalError::MisplacedSeparator(span) => span,
+            EvalError::Arithmetic(_, span) => span,
+            EvalError::UnknownVariable(ref name) => name.span,
+            EvalError::UnknownFunction(ref name) => name.span,
+            EvalError::WrongArgumentCount { ref function, .. } => function.span,
+            EvalError::RecursionLimit(span) => span,
+            EvalError::ConstantAssignment(ref name) => name.span,
+            EvalError::ExpectedNumber(span) => span,
+            EvalError::ExpectedBoolean(span) => span,
+            EvalError::MissingValue(span) => span
+        }
+    }
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            EvalError::UnexpectedCharacter(ref character) => write!(f, "unexpected character `{}`", character.node),
+            EvalError::UnexpectedToken(ref token) => write!(f, "unexpected token `{}`", token.node),
+            EvalError::MismatchedParenthesis(_) => write!(f, "mismatched parenthesis"),
+            EvalError::MissingOperand(_) => write!(f, "missing operand"),
+            EvalError::TrailingOperand(_) => write!(f, "trailing operand"),
+            EvalError::TooDeep(_) => write!(f, "expression nested too deeply"),
+            EvalError::InvalidLiteral(ref literal) => write!(f, "invalid literal `{}`", literal.node),
+            EvalError::MisplacedSeparator(_) => write!(f, "misplaced digit separator"),
+            EvalError::Arithmetic(ref error, _) => write!(f, "{}", error),
+            EvalError::UnknownVariable(ref name) => write!(f, "unknown variable `{}`", name.node),
+            EvalError::UnknownFunction(ref name) => write!(f, "unknown function `{}`", name.node),
+            EvalError::WrongArgumentCount { ref function, expected, found } => write!(
+                f,
+                "function `{}` takes {} argument{} but {} {} given",
+                function.node,
+                expected,
+                if expected == 1 { "" } else { "s" },
+                found,
+                if found == 1 { "was" } else { "were" }
+            ),
+            EvalError::RecursionLimit(_) => write!(f, "maximum call depth exceeded"),
+            EvalError::ConstantAssignment(ref name) => write!(f, "cannot assign to constant `{}`", name.node),
+            EvalError::ExpectedNumber(_) => write!(f, "expected a number, found a boolean"),
+            EvalError::ExpectedBoolean(_) => write!(f, "expected a boolean, found a number"),
+            EvalError::MissingValue(_) => write!(f, "function definition has no value")
+        }?;
+        write!(f, " at {}", self.span())
+    }
+}
+
+impl Error for EvalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokenizer::token::operator::Operator;
+
+    #[test]
+    fn each_error_has_a_human_readable_message() {
+        let span: Span = Span::new(4, 1, 2, 3);
+        let result: Vec<String> = [
+            EvalError::UnexpectedCharacter(Spanned::new('$', span)),
+            EvalError::UnexpectedToken(Spanned::new(Token::Operator(Operator::Times), span)),
+            EvalError::MismatchedParenthesis(span),
+            EvalError::MissingOperand(span),
+            EvalError::TrailingOperand(span),
+            EvalError::TooDeep(span),
+            EvalError::InvalidLiteral(Spanned::new(String::from("1.2.3"), span)),
+            EvalError::MisplacedSeparator(span),
+            EvalError::Arithmetic(ArithmeticError::DivisionByZero, span),
+            EvalError::UnknownVariable(Spanned::new(String::from("x"), span)),
+            EvalError::UnknownFunction(Spanned::new(String::from("f"), span)),
+            EvalError::WrongArgumentCount { function: Spanned::new(String::from("f"), span), expected: 1, found: 2 },
+            EvalError::WrongArgumentCount { function: Spanned::new(String::from("f"), span), expected: 2, found: 1 },
+            EvalError::RecursionLimit(span),
+            EvalError::ConstantAssignment(Spanned::new(String::from("pi"), span)),
+            EvalError::ExpectedNumber(span),
+            EvalError::ExpectedBoolean(span),
+            EvalError::MissingValue(span)
+        ]
+        .iter()
+        .map(|error| error.to_string())
+        .collect();
+
+        let expected_result: Vec<String> = [
+            "unexpected character `$` at 2:3",
+            "unexpected token `*` at 2:3",
+            "mismatched parenthesis at 2:3",
+            "missing operand at 2:3",
+            "trailing operand at 2:3",
+            "expression nested too deeply at 2:3",
+            "invalid literal `1.2.3` at 2:3",
+            "misplaced digit separator at 2:3",
+            "division by zero at 2:3",
+            "unknown variable `x` at 2:3",
+            "unknown function `f` at 2:3",
+            "function `f` takes 1 argument but 2 were given at 2:3",
+            "function `f` takes 2 arguments but 1 was given at 2:3",
+            "maximum call depth exceeded at 2:3",
+            "cannot assign to constant `pi` at 2:3",
+            "expected a number, found a boolean at 2:3",
+            "expected a boolean, found a number at 2:3",
+            "function definition has no value at 2:3"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,97 @@
+use number::{ArithmeticError, Number};
+
+// Function every expression may call without defining it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BuiltIn {
+    Sqrt, Sin, Cos, Tan, Ln, Log10, Exp, Abs, Floor, Ceil, Round, Min, Max
+}
+
+const BUILT_INS: [(&str, BuiltIn); 13] = [
+    ("sqrt", BuiltIn::Sqrt),
+    ("sin", BuiltIn::Sin),
+    ("cos", BuiltIn::Cos),
+    ("tan", BuiltIn::Tan),
+    ("ln", BuiltIn::Ln),
+    ("log10", BuiltIn::Log10),
+    ("exp", BuiltIn::Exp),
+    ("abs", BuiltIn::Abs),
+    ("floor", BuiltIn::Floor),
+    ("ceil", BuiltIn::Ceil),
+    ("round", BuiltIn::Round),
+    ("min", BuiltIn::Min),
+    ("max", BuiltIn::Max)
+];
+
+impl BuiltIn {
+    pub fn from_name(name: &str) -> Option<BuiltIn> {
+        BUILT_INS.iter().find(|&&(built_in, _)| built_in == name).map(|&(_, built_in)| built_in)
+    }
+
+    #[cfg(test)]
+    fn name(&self) -> &'static str {
+        BUILT_INS.iter().find(|&&(_, built_in)| built_in == *self).map(|&(name, _)| name).unwrap()
+    }
+
+    pub fn arity(&self) -> usize {
+        match *self {
+            BuiltIn::Min | BuiltIn::Max => 2,
+            _ => 1
+        }
+    }
+
+    // Applies the function to `arguments`, which the interpreter checked to
+    // be as many as its arity.
+    pub(crate) fn call<N: Number>(&self, arguments: Vec<N>) -> Result<N, ArithmeticError> {
+        let mut arguments = arguments.into_iter();
+        let first_argument: N = arguments.next().unwrap();
+
+        let function: fn(N) -> Result<N, ArithmeticError> = match *self {
+            BuiltIn::Sqrt => N::sqrt,
+            BuiltIn::Sin => N::sin,
+            BuiltIn::Cos => N::cos,
+            BuiltIn::Tan => N::tan,
+            BuiltIn::Ln => N::ln,
+            BuiltIn::Log10 => N::log10,
+            BuiltIn::Exp => N::exp,
+            BuiltIn::Abs => N::absolute_value,
+            BuiltIn::Floor => N::floor,
+            BuiltIn::Ceil => N::ceil,
+            BuiltIn::Round => N::round,
+            BuiltIn::Min => return first_argument.minimum(arguments.next().unwrap()),
+            BuiltIn::Max => return first_argument.maximum(arguments.next().unwrap())
+        };
+        function(first_argument)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_ins_are_found_by_name() {
+        let result: Vec<Option<BuiltIn>> = ["sqrt", "log10", "max", "log", "Sqrt"]
+            .iter()
+            .map(|name| BuiltIn::from_name(name))
+            .collect();
+
+        let expected_result: Vec<Option<BuiltIn>> = [
+            Some(BuiltIn::Sqrt),
+            Some(BuiltIn::Log10),
+            Some(BuiltIn::Max),
+            None,
+            None
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+        assert_eq!(BuiltIn::Log10.name(), "log10");
+    }
+
+    #[test]
+    fn built_ins_are_applied_to_their_arguments() {
+        assert_eq!(BuiltIn::Sqrt.call(vec![9.0f64]), Ok(3.0));
+        assert_eq!(BuiltIn::Abs.call(vec![-3i64]), Ok(3));
+        assert_eq!(BuiltIn::Max.call(vec![2i64, 5]), Ok(5));
+        assert_eq!(BuiltIn::Sin.call(vec![1i64]), Err(ArithmeticError::NotRepresentable));
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use number::Number;
+use number::tolerance::Tolerance;
+use parser::expr::Expr;
+
+// Function defined by the user, as in `f(x, y) = x^2 + y`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Function {
+    pub parameters: Vec<String>,
+    pub body: Expr
+}
+
+// Calls deeper than this are most likely runaway recursions. Each nested call
+// takes a few kilobytes of stack, and this many fit on the 2 MB of a spawned
+// thread even in debug builds.
+const DEFAULT_MAX_DEPTH: usize = 256;
+
+// Deepest calls may nest whatever the context is set to. Calls recurse on the
+// native stack, and this many fit on the 8 MB of a main thread even in debug
+// builds, with room to spare.
+pub const MAX_DEPTH_LIMIT: usize = 1_000;
+
+// What an expression is evaluated against: the values of the variables it
+// refers to, the functions it may call, the settings of the number type and
+// the tolerance of `~=`.
+#[derive(Clone, Debug)]
+pub struct Context<N: Number> {
+    settings: N::Settings,
+    variables: HashMap<String, N>,
+    functions: HashMap<String, Rc<Function>>,
+    // Arguments of the functions being called, innermost call last.
+    scopes: Vec<HashMap<String, N>>,
+    max_depth: usize,
+    tolerance: Tolerance
+}
+
+impl<N: Number> Context<N> {
+    pub fn new() -> Context<N> {
+        Context::with_settings(N::Settings::default())
+    }
+
+    pub fn with_settings(settings: N::Settings) -> Context<N> {
+        Context {
+            settings,
+            variables: HashMap::new(),
+            functions: HashMap::new(),
+            scopes: Vec::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            tolerance: Tolerance::default()
+        }
+    }
+
+    pub fn settings(&self) -> &N::Settings {
+        &self.settings
+    }
+
+    // Value of `name`, looked up in the arguments of the innermost function
+    // call first.
+    pub fn get(&self, name: &str) -> Option<&N> {
+        self.scopes
+            .last()
+            .and_then(|scope| scope.get(name))
+            .or_else(|| self.variables.get(name))
+    }
+
+    // Binds `name` to `value`, returning the value it was bound to before.
+    pub fn set(&mut self, name: &str, value: N) -> Option<N> {
+        self.variables.insert(String::from(name), value)
+    }
+
+    pub fn unset(&mut self, name: &str) -> Option<N> {
+        self.variables.remove(name)
+    }
+
+    pub fn function(&self, name: &str) -> Option<Rc<Function>> {
+        self.functions.get(name).cloned()
+    }
+
+    // Defines the function `name`, replacing any previous definition.
+    pub fn define(&mut self, name: &str, function: Function) {
+        self.functions.insert(String::from(name), Rc::new(function));
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    // Maximum number of nested function calls, beyond which evaluation
+    // fails, at most `MAX_DEPTH_LIMIT`. Higher limits than the default one
+    // may need a larger stack than that of a spawned thread.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth.min(MAX_DEPTH_LIMIT);
+    }
+
+    pub fn tolerance(&self) -> &Tolerance {
+        &self.tolerance
+    }
+
+    // How close values have to be for `~=` to hold.
+    pub fn set_tolerance(&mut self, tolerance: Tolerance) {
+        self.tolerance = tolerance;
+    }
+
+    // Enters a function call with the given arguments, unless the maximum
+    // depth is reached.
+    pub(crate) fn push_scope(&mut self, arguments: HashMap<String, N>) -> bool {
+        if self.scopes.len() >= self.max_depth {
+            return false;
+        }
+
+        self.scopes.push(arguments);
+        true
+    }
+
+    pub(crate) fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+}
+
+impl<N: Number> Default for Context<N> {
+    fn default() -> Context<N> {
+        Context::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variables_can_be_bound_and_rebound() {
+        let mut context: Context<i64> = Context::new();
+
+        assert_eq!(context.set("x", 1), None);
+        assert_eq!(context.set("x", 2), Some(1));
+        assert_eq!(context.get("x"), Some(&2));
+        assert_eq!(context.unset("x"), Some(2));
+        assert_eq!(context.get("x"), None);
+    }
+
+    #[test]
+    fn arguments_shadow_variables_until_the_call_ends() {
+        let mut context: Context<i64> = Context::new();
+        context.set("x", 1);
+        context.set("y", 2);
+
+        assert!(context.push_scope([(String::from("x"), 10)].iter().cloned().collect()));
+        assert_eq!((context.get("x"), context.get("y")), (Some(&10), Some(&2)));
+
+        context.pop_scope();
+        assert_eq!(context.get("x"), Some(&1));
+    }
+
+    #[test]
+    fn calls_cannot_nest_deeper_than_the_maximum_depth() {
+        let mut context: Context<i64> = Context::new();
+        context.set_max_depth(2);
+
+        let result: Vec<bool> = (0..3).map(|_| context.push_scope(HashMap::new())).collect();
+
+        assert_eq!(result, [true, true, false]);
+    }
+
+    #[test]
+    fn the_maximum_depth_is_limited() {
+        let mut context: Context<i64> = Context::new();
+        context.set_max_depth(10_000_000);
+
+        assert_eq!(context.max_depth(), MAX_DEPTH_LIMIT);
+    }
+}
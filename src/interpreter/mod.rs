@@ -1,39 +1,412 @@
-use tokenizer::token::Token;
-
-pub fn interpret(tokens: Vec<Token>) -> f32 {
-    let mut operands: Vec<f32> = Vec::new();
-
-	for token in tokens {
-		match token {
-			Token::Operator(operator) => {
-                let second_operand = operands.pop().unwrap();
-                let first_operand = operands.pop().unwrap();
-                let result: f32 = operator.call(first_operand, second_operand);
-
-                operands.push(result);
-			}
-            Token::Literal(literal) => {
-                let operand: f32 = literal.to_string().parse::<f32>().unwrap();
-                operands.push(operand);
+pub mod builtin;
+pub mod context;
+pub mod value;
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use self::builtin::BuiltIn;
+use self::context::{Context, Function};
+use self::value::Value;
+use error::EvalError;
+use number::Number;
+use number::constant::Constant;
+use parser::expr::Expr;
+use tokenizer::span::{Span, Spanned};
+use tokenizer::token::literal::Literal;
+use tokenizer::token::operator::Operator;
+use tokenizer::token::unary_operator::UnaryOperator;
+
+// Value of `i` for the number types having an imaginary unit. For the
+// others, `i` is a name like any other.
+fn imaginary_unit<N: Number>(name: &str, context: &Context<N>) -> Option<N> {
+    if name != "i" { return None; }
+
+    N::from_literal(&Literal::imaginary_unit(), context.settings()).ok()
+}
+
+// Fails when assigning to `name` would hide a constant.
+fn check_assignable<N: Number>(name: &Spanned<String>, context: &Context<N>) -> Result<(), EvalError> {
+    if Constant::from_name(&name.node).is_some() || imaginary_unit(&name.node, context).is_some() {
+        return Err(EvalError::ConstantAssignment(name.clone()));
+    }
+
+    Ok(())
+}
+
+// Keeps the function defined by `definition` in `context`.
+fn define<N: Number>(definition: &Expr, context: &mut Context<N>) -> Result<(), EvalError> {
+    if let Expr::Definition { ref name, ref parameters, ref body } = *definition {
+        for parameter in parameters {
+            check_assignable(parameter, context)?;
+        }
+
+        let parameters: Vec<String> = parameters.iter().map(|parameter| parameter.node.clone()).collect();
+        context.define(&name.node, Function { parameters, body: (**body).clone() });
+    }
+
+    Ok(())
+}
+
+// Checks that `function` is given as many arguments as it expects.
+fn check_arity(function: &Spanned<String>, expected: usize, found: usize) -> Result<(), EvalError> {
+    if expected != found {
+        return Err(EvalError::WrongArgumentCount { function: function.clone(), expected, found });
+    }
+
+    Ok(())
+}
+
+// Outcome of the comparison `op` between the values of `lhs` and `rhs`,
+// which have to be numbers unless `op` is `==` or `!=` between booleans.
+fn compare<N: Number>(
+    op: &Spanned<Operator>,
+    first_operand: Value<N>,
+    lhs: &Expr,
+    second_operand: Value<N>,
+    rhs: &Expr,
+    context: &Context<N>
+) -> Result<bool, EvalError> {
+    if let Value::Boolean(first_operand) = first_operand {
+        if op.node == Operator::Equal || op.node == Operator::NotEqual {
+            let second_operand: bool = second_operand.boolean(rhs.span())?;
+
+            return Ok((first_operand == second_operand) == (op.node == Operator::Equal));
+        }
+    }
+    let first_operand: N = first_operand.number(lhs.span())?;
+    let second_operand: N = second_operand.number(rhs.span())?;
+
+    op.node.compare(&first_operand, &second_operand, context.tolerance(), context.settings())
+        .map_err(|error| EvalError::Arithmetic(error, op.span))
+}
+
+// Function a call refers to.
+enum Callee {
+    User(Rc<Function>),
+    BuiltIn(BuiltIn)
+}
+
+// Step left in the evaluation of an expression. The tasks completing an
+// expression are scheduled before those evaluating its operands, whose values
+// they then find on top of the stack of values.
+enum Task<'a> {
+    Evaluate(&'a Expr),
+    // Fails unless the value just computed, that of the expression, is a
+    // number. Checked before evaluating the next operand, which may fail too.
+    ExpectNumber(&'a Expr),
+    ExpectBoolean(&'a Expr),
+    Unary(&'a Spanned<UnaryOperator>, &'a Expr),
+    Binary(&'a Spanned<Operator>, &'a Expr, &'a Expr),
+    // Evaluates the second operand of a logical operator only when the first
+    // one does not decide the result, as `false` does for `&&`.
+    Logical(&'a Spanned<Operator>, &'a Expr, &'a Expr),
+    // Compares the operand of a chain at the given index, already evaluated,
+    // to the next one as long as the comparisons before it hold.
+    Link(&'a [Expr], &'a [Spanned<Operator>], usize),
+    Compare(&'a [Expr], &'a [Spanned<Operator>], usize),
+    Assign(&'a Spanned<String>, &'a Expr),
+    Call(Callee, usize, Span),
+    Interval(&'a Expr, &'a Expr, Span),
+    // Evaluates only the value selected by the condition.
+    Select(&'a Expr, &'a Expr, &'a Expr),
+    Define(&'a Expr),
+    // Drops the value of a statement followed by others.
+    Discard
+}
+
+// Value the last task left, which computed that of `expr`.
+fn pop<N: Number>(values: &mut Vec<Value<N>>, expr: &Expr) -> Result<Value<N>, EvalError> {
+    values.pop().ok_or_else(|| EvalError::MissingValue(expr.span()))
+}
+
+// Value of `callee` for the given arguments. The body of a function defined
+// by the user is evaluated with its parameters holding them.
+fn call<N: Number>(callee: Callee, arguments: Vec<Value<N>>, span: Span, context: &mut Context<N>) -> Result<Value<N>, EvalError> {
+    let arguments: Vec<N> = arguments
+        .into_iter()
+        .map(|argument| argument.number(span))
+        .collect::<Result<Vec<N>, EvalError>>()?;
+
+    let function: Rc<Function> = match callee {
+        Callee::User(function) => function,
+        Callee::BuiltIn(built_in) => {
+            return built_in.call(arguments)
+                .map(Value::Number)
+                .map_err(|error| EvalError::Arithmetic(error, span));
+        }
+    };
+    let scope: HashMap<String, N> = function.parameters.iter().cloned().zip(arguments).collect();
+
+    if !context.push_scope(scope) { return Err(EvalError::RecursionLimit(span)); }
+    let result: Result<Value<N>, EvalError> = interpret_value_with(&function.body, context);
+    context.pop_scope();
+
+    result
+}
+
+// Pushes the value of `expr` when it has no operands, otherwise schedules the
+// evaluation of its operands followed by the task completing it.
+fn evaluate<'a, N: Number>(
+    expr: &'a Expr,
+    tasks: &mut Vec<Task<'a>>,
+    values: &mut Vec<Value<N>>,
+    context: &mut Context<N>
+) -> Result<(), EvalError> {
+    match *expr {
+        Expr::Number(ref literal) => {
+            let value: N = N::from_literal(&literal.node, context.settings())
+                .map_err(|error| EvalError::Arithmetic(error, literal.span))?;
+            values.push(Value::Number(value));
+        },
+        Expr::Variable(ref name) => {
+            let value: N = if let Some(constant) = Constant::from_name(&name.node) {
+                N::constant(constant, context.settings()).map_err(|error| EvalError::Arithmetic(error, name.span))?
+            } else if let Some(unit) = imaginary_unit(&name.node, context) {
+                unit
+            } else {
+                context.get(&name.node).cloned().ok_or_else(|| EvalError::UnknownVariable(name.clone()))?
+            };
+            values.push(Value::Number(value));
+        },
+        Expr::Unary { ref op, ref operand } => {
+            tasks.push(Task::Unary(op, operand));
+            tasks.push(Task::Evaluate(operand));
+        },
+        Expr::Binary { ref op, ref lhs, ref rhs } if op.node.is_logical() => {
+            tasks.push(Task::Logical(op, lhs, rhs));
+            tasks.push(Task::Evaluate(lhs));
+        },
+        Expr::Binary { ref op, ref lhs, ref rhs } => {
+            tasks.push(Task::Binary(op, lhs, rhs));
+            tasks.push(Task::Evaluate(rhs));
+            if !op.node.is_comparison() { tasks.push(Task::ExpectNumber(lhs)); }
+            tasks.push(Task::Evaluate(lhs));
+        },
+        Expr::Chain { ref operands, ref ops } => match operands.first() {
+            Some(first) => {
+                tasks.push(Task::Link(operands, ops, 0));
+                tasks.push(Task::Evaluate(first));
+            },
+            None => values.push(Value::Boolean(true))
+        },
+        // Variables only hold numbers.
+        Expr::Assignment { ref name, ref value } => {
+            check_assignable(name, context)?;
+            tasks.push(Task::Assign(name, value));
+            tasks.push(Task::Evaluate(value));
+        },
+        Expr::Call { ref name, ref arguments, span } => {
+            // Functions defined by the user take precedence over built-ins.
+            let (callee, arity): (Callee, usize) = match context.function(&name.node) {
+                Some(function) => {
+                    let arity: usize = function.parameters.len();
+                    (Callee::User(function), arity)
+                },
+                None => {
+                    let built_in: BuiltIn = BuiltIn::from_name(&name.node)
+                        .ok_or_else(|| EvalError::UnknownFunction(name.clone()))?;
+                    (Callee::BuiltIn(built_in), built_in.arity())
+                }
+            };
+            check_arity(name, arity, arguments.len())?;
+
+            // Arguments are evaluated in the scope of the caller.
+            tasks.push(Task::Call(callee, arguments.len(), span));
+            for argument in arguments.iter().rev() {
+                tasks.push(Task::ExpectNumber(argument));
+                tasks.push(Task::Evaluate(argument));
+            }
+        },
+        Expr::Definition { ref name, .. } => {
+            define(expr, context)?;
+            return Err(EvalError::MissingValue(name.span));
+        },
+        // Only the value of the last statement is kept. A definition gives
+        // none, which is an error when it comes last.
+        Expr::Sequence(ref statements) => {
+            let last: usize = statements.len().saturating_sub(1);
+            for (index, statement) in statements.iter().enumerate().rev() {
+                match *statement {
+                    Expr::Definition { .. } if index != last => tasks.push(Task::Define(statement)),
+                    _ if index != last => {
+                        tasks.push(Task::Discard);
+                        tasks.push(Task::Evaluate(statement));
+                    },
+                    _ => tasks.push(Task::Evaluate(statement))
+                }
             }
-            _ => panic!("Dev error: The AST should only contains Operators and Literals."),
-		}
-	}
+        },
+        Expr::Interval { ref lower, ref upper, span } => {
+            tasks.push(Task::Interval(lower, upper, span));
+            tasks.push(Task::Evaluate(upper));
+            tasks.push(Task::ExpectNumber(lower));
+            tasks.push(Task::Evaluate(lower));
+        },
+        Expr::Conditional { ref condition, ref then, ref otherwise } => {
+            tasks.push(Task::Select(condition, then, otherwise));
+            tasks.push(Task::Evaluate(condition));
+        }
+    }
 
-    operands.pop().unwrap()
+    Ok(())
+}
+
+// Carries out `task`, except for a call which is handed back. The function
+// called may call others in turn, and the frame of `step` is large.
+fn step<'a, N: Number>(
+    task: Task<'a>,
+    tasks: &mut Vec<Task<'a>>,
+    values: &mut Vec<Value<N>>,
+    context: &mut Context<N>
+) -> Result<Option<Task<'a>>, EvalError> {
+    match task {
+        Task::Evaluate(expr) => evaluate(expr, tasks, values, context)?,
+        Task::ExpectNumber(expr) => {
+            if let Some(&Value::Boolean(_)) = values.last() {
+                return Err(EvalError::ExpectedNumber(expr.span()));
+            }
+        },
+        Task::ExpectBoolean(expr) => {
+            if let Some(&Value::Number(_)) = values.last() {
+                return Err(EvalError::ExpectedBoolean(expr.span()));
+            }
+        },
+        Task::Unary(op, operand) if op.node == UnaryOperator::Not => {
+            let operand: bool = pop(values, operand)?.boolean(operand.span())?;
+            values.push(Value::Boolean(!operand));
+        },
+        Task::Unary(op, operand) => {
+            let operand: N = pop(values, operand)?.number(operand.span())?;
+            let value: N = op.node.call(operand).map_err(|error| EvalError::Arithmetic(error, op.span))?;
+            values.push(Value::Number(value));
+        },
+        Task::Binary(op, lhs, rhs) if op.node.is_comparison() => {
+            let second_operand: Value<N> = pop(values, rhs)?;
+            let first_operand: Value<N> = pop(values, lhs)?;
+            let value: bool = compare(op, first_operand, lhs, second_operand, rhs, context)?;
+            values.push(Value::Boolean(value));
+        },
+        Task::Binary(op, lhs, rhs) => {
+            let second_operand: N = pop(values, rhs)?.number(rhs.span())?;
+            let first_operand: N = pop(values, lhs)?.number(lhs.span())?;
+            let value: N = op.node.call(first_operand, second_operand)
+                .map_err(|error| EvalError::Arithmetic(error, op.span))?;
+            values.push(Value::Number(value));
+        },
+        Task::Logical(op, lhs, rhs) => {
+            let first_operand: bool = pop(values, lhs)?.boolean(lhs.span())?;
+            if first_operand == (op.node == Operator::Or) {
+                values.push(Value::Boolean(first_operand));
+            } else {
+                tasks.push(Task::ExpectBoolean(rhs));
+                tasks.push(Task::Evaluate(rhs));
+            }
+        },
+        Task::Link(operands, ops, index) => match operands.get(index + 1) {
+            Some(rhs) if index < ops.len() => {
+                tasks.push(Task::Compare(operands, ops, index));
+                tasks.push(Task::Evaluate(rhs));
+            },
+            _ => {
+                values.pop();
+                values.push(Value::Boolean(true));
+            }
+        },
+        Task::Compare(operands, ops, index) => {
+            let (lhs, rhs): (&Expr, &Expr) = (&operands[index], &operands[index + 1]);
+            let second_operand: Value<N> = pop(values, rhs)?;
+            let first_operand: Value<N> = pop(values, lhs)?;
+
+            if compare(&ops[index], first_operand, lhs, second_operand.clone(), rhs, context)? {
+                values.push(second_operand);
+                tasks.push(Task::Link(operands, ops, index + 1));
+            } else {
+                values.push(Value::Boolean(false));
+            }
+        },
+        Task::Assign(name, value) => {
+            let value: N = pop(values, value)?.number(value.span())?;
+            context.set(&name.node, value.clone());
+            values.push(Value::Number(value));
+        },
+        Task::Interval(lower, upper, span) => {
+            let upper_bound: N = pop(values, upper)?.number(upper.span())?;
+            let lower_bound: N = pop(values, lower)?.number(lower.span())?;
+            let value: N = N::interval(lower_bound, upper_bound)
+                .map_err(|error| EvalError::Arithmetic(error, span))?;
+            values.push(Value::Number(value));
+        },
+        Task::Select(condition, then, otherwise) => {
+            if pop(values, condition)?.boolean(condition.span())? {
+                tasks.push(Task::Evaluate(then));
+            } else {
+                tasks.push(Task::Evaluate(otherwise));
+            }
+        },
+        Task::Define(definition) => define(definition, context)?,
+        Task::Discard => { values.pop(); },
+        Task::Call(..) => return Ok(Some(task))
+    }
+
+    Ok(None)
+}
+
+pub fn interpret<N: Number>(ast: &Expr) -> Result<N, EvalError> {
+    interpret_with(ast, &mut Context::new())
+}
+
+// Same as `interpret`, with the variables and the number type settings of
+// `context`. Assignments are kept in `context` afterwards.
+pub fn interpret_with<N: Number>(ast: &Expr, context: &mut Context<N>) -> Result<N, EvalError> {
+    interpret_value_with(ast, context)?.number(ast.span())
+}
+
+// Same as `interpret`, for expressions which may also give a boolean, such
+// as `1 < 2`.
+pub fn interpret_value<N: Number>(ast: &Expr) -> Result<Value<N>, EvalError> {
+    interpret_value_with(ast, &mut Context::new())
+}
+
+// The tree is walked with a stack of tasks rather than recursively, so that
+// deep expressions fit in memory. Only calls to functions defined by the user
+// recurse, as deep as `Context::max_depth`.
+pub fn interpret_value_with<N: Number>(ast: &Expr, context: &mut Context<N>) -> Result<Value<N>, EvalError> {
+    let mut tasks: Vec<Task> = vec![Task::Evaluate(ast)];
+    let mut values: Vec<Value<N>> = Vec::new();
+
+    while let Some(task) = tasks.pop() {
+        if let Some(Task::Call(callee, count, span)) = step(task, &mut tasks, &mut values, context)? {
+            let arguments: Vec<Value<N>> = values.split_off(values.len().saturating_sub(count));
+            let value: Value<N> = call(callee, arguments, span, context)?;
+            values.push(value);
+        }
+    }
+
+    values.pop().ok_or_else(|| EvalError::MissingValue(ast.span()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::context::MAX_DEPTH_LIMIT;
+    use number::ArithmeticError;
+    use number::complex::Complex;
+    use number::interval::Interval;
+    use number::tolerance::Tolerance;
+    use number::uncertain::Uncertain;
+    use number::decimal::{Decimal, DecimalSettings, Rounding};
     use parser::parse;
+    use tokenizer::span::{Span, Spanned};
+    use tokenizer::token::literal::Literal;
+    use tokenizer::token::Token;
     use tokenizer::tokenize;
 
     #[test]
     fn interpret_simple_addition() {
-        let tokens: Vec<Token> = tokenize("3+2");
-        let ast: Vec<Token> = parse(tokens);
-        let result: f32 = interpret(ast);
+        let tokens: Vec<Spanned<Token>> = tokenize("3+2").unwrap();
+        let ast: Expr = parse(tokens).unwrap();
+        let result: f32 = interpret(&ast).unwrap();
 
         let expected_result: f32 = 5.0;
 
@@ -42,9 +415,9 @@ mod tests {
 
     #[test]
     fn interpret_simple_substraction() {
-        let tokens: Vec<Token> = tokenize("3-2");
-        let ast: Vec<Token> = parse(tokens);
-        let result: f32 = interpret(ast);
+        let tokens: Vec<Spanned<Token>> = tokenize("3-2").unwrap();
+        let ast: Expr = parse(tokens).unwrap();
+        let result: f32 = interpret(&ast).unwrap();
 
         let expected_result: f32 = 1.0;
 
@@ -53,9 +426,9 @@ mod tests {
 
     #[test]
     fn interpret_simple_multiplication() {
-        let tokens: Vec<Token> = tokenize("3*2");
-        let ast: Vec<Token> = parse(tokens);
-        let result: f32 = interpret(ast);
+        let tokens: Vec<Spanned<Token>> = tokenize("3*2").unwrap();
+        let ast: Expr = parse(tokens).unwrap();
+        let result: f32 = interpret(&ast).unwrap();
 
         let expected_result: f32 = 6.0;
 
@@ -64,9 +437,9 @@ mod tests {
 
     #[test]
     fn interpret_simple_integer_division() {
-        let tokens: Vec<Token> = tokenize("6/2");
-        let ast: Vec<Token> = parse(tokens);
-        let result: f32 = interpret(ast);
+        let tokens: Vec<Spanned<Token>> = tokenize("6/2").unwrap();
+        let ast: Expr = parse(tokens).unwrap();
+        let result: f32 = interpret(&ast).unwrap();
 
         let expected_result: f32 = 3.0;
 
@@ -75,9 +448,9 @@ mod tests {
 
     #[test]
     fn interpret_simple_exponentiation() {
-        let tokens: Vec<Token> = tokenize("2^3");
-        let ast: Vec<Token> = parse(tokens);
-        let result: f32 = interpret(ast);
+        let tokens: Vec<Spanned<Token>> = tokenize("2^3").unwrap();
+        let ast: Expr = parse(tokens).unwrap();
+        let result: f32 = interpret(&ast).unwrap();
 
         let expected_result: f32 = 8.0;
 
@@ -86,12 +459,599 @@ mod tests {
 
     #[test]
     fn interpret_complex_operation() {
-        let tokens: Vec<Token> = tokenize("(2+2)*3^2/3");
-        let ast: Vec<Token> = parse(tokens);
-        let result: f32 = interpret(ast);
+        let tokens: Vec<Spanned<Token>> = tokenize("(2+2)*3^2/3").unwrap();
+        let ast: Expr = parse(tokens).unwrap();
+        let result: f32 = interpret(&ast).unwrap();
 
         let expected_result: f32 = 12.0;
 
        assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn interpret_chained_operations_of_equal_precedence() {
+        let result: Vec<f32> = ["3-2-1", "8/4/2", "2^3^2"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()).unwrap())
+            .collect();
+
+        let expected_result: [f32; 3] = [0.0, 1.0, 512.0];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_unary_operators() {
+        let result: Vec<f32> = ["-3", "2*-3", "-(1+2)", "-2^2", "+2-+1"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()).unwrap())
+            .collect();
+
+        let expected_result: [f32; 5] = [-3.0, -6.0, -3.0, -4.0, 1.0];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_decimal_and_scientific_literals() {
+        let result: Vec<f32> = ["1.5*2", ".25", "1e-3*1000", "2.5E2"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()).unwrap())
+            .collect();
+
+        let expected_result: [f32; 4] = [3.0, 0.25, 1.0, 250.0];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_hexadecimal_binary_and_octal_literals() {
+        let result: Vec<f32> = ["0x1F", "0xff", "0b1010", "0o17"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()).unwrap())
+            .collect();
+
+        let expected_result: [f32; 4] = [31.0, 255.0, 10.0, 15.0];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_with_double_precision() {
+        let tokens: Vec<Spanned<Token>> = tokenize("16777217+0.1").unwrap();
+        let ast: Expr = parse(tokens).unwrap();
+        let result: f64 = interpret(&ast).unwrap();
+
+        let expected_result: f64 = 16777217.1;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_with_integers() {
+        let result: Vec<Result<i64, EvalError>> = ["7/2*2", "2^62+(2^62-1)", "1/(2-2)", "2.5"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<i64, EvalError>> = [
+            Ok(6),
+            Ok(i64::MAX),
+            Err(EvalError::Arithmetic(ArithmeticError::DivisionByZero, Span::new(1, 1, 1, 2))),
+            Err(EvalError::Arithmetic(ArithmeticError::NotRepresentable, Span::new(0, 3, 1, 1)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_with_settings_of_the_number_type() {
+        let settings: DecimalSettings = DecimalSettings { scale: 3, rounding: Rounding::Truncate };
+        let ast: Expr = parse(tokenize("2/3").unwrap()).unwrap();
+        let result: Decimal = interpret_with(&ast, &mut Context::with_settings(settings)).unwrap();
+
+        assert_eq!(result.to_string(), "0.666");
+    }
+
+    #[test]
+    fn interpret_with_complex_numbers() {
+        let ast: Expr = parse(tokenize("(1+2i)*(3-i)").unwrap()).unwrap();
+        let result: Complex = interpret(&ast).unwrap();
+
+        assert_eq!(result.to_string(), "5+5i");
+    }
+
+    #[test]
+    fn interpret_i_as_a_variable_without_imaginary_unit() {
+        let result: Vec<Result<f64, EvalError>> = ["i = 2; i * 3", "f(i) = i * 2; f(4)", "i"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<f64, EvalError>> = [
+            Ok(6.0),
+            Ok(8.0),
+            Err(EvalError::UnknownVariable(Spanned::new(String::from("i"), Span::new(0, 1, 1, 1))))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+
+        let complex: Result<Complex, EvalError> = interpret(&parse(tokenize("i = 2").unwrap()).unwrap());
+        assert_eq!(complex, Err(EvalError::ConstantAssignment(Spanned::new(String::from("i"), Span::new(0, 1, 1, 1)))));
+    }
+
+    #[test]
+    fn interpret_rejects_imaginary_literals_with_real_numbers() {
+        let ast: Expr = parse(tokenize("1+2i").unwrap()).unwrap();
+        let result: Result<f64, EvalError> = interpret(&ast);
+
+        assert_eq!(result, Err(EvalError::Arithmetic(ArithmeticError::NotRepresentable, Span::new(2, 2, 1, 3))));
+    }
+
+    #[test]
+    fn interpret_with_intervals() {
+        let result: Vec<Result<Interval, EvalError>> = ["[1, 2] * [-1, 3] + 1", "1 / [0, 2]", "[2, 1]"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Interval, EvalError>> = [
+            Ok(Interval::new(-1.0, 7.0).unwrap()),
+            Ok(Interval::new(0.5, f64::INFINITY).unwrap()),
+            Err(EvalError::Arithmetic(ArithmeticError::EmptyInterval, Span::new(0, 6, 1, 1)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_with_uncertainties() {
+        let ast: Expr = parse(tokenize("(9.81 ± 0.02) * (2.0 +/- 0.1)").unwrap()).unwrap();
+        let result: Uncertain = interpret(&ast).unwrap();
+
+        assert_eq!(result.to_string(), "19.62 ± 0.98");
+    }
+
+    #[test]
+    fn interpret_rejects_intervals_with_other_numbers() {
+        let ast: Expr = parse(tokenize("[1, 2]").unwrap()).unwrap();
+        let result: Result<f64, EvalError> = interpret(&ast);
+
+        assert_eq!(result, Err(EvalError::Arithmetic(ArithmeticError::NotRepresentable, Span::new(0, 6, 1, 1))));
+    }
+
+    #[test]
+    fn interpret_with_variables_from_the_context() {
+        let ast: Expr = parse(tokenize("x*2 + y").unwrap()).unwrap();
+        let mut context: Context<f64> = Context::new();
+        context.set("x", 3.0);
+        context.set("y", 0.5);
+
+        assert_eq!(interpret_with(&ast, &mut context), Ok(6.5));
+
+        context.set("x", 1.0);
+        assert_eq!(interpret_with(&ast, &mut context), Ok(2.5));
+
+        context.unset("y");
+        assert_eq!(
+            interpret_with(&ast, &mut context),
+            Err(EvalError::UnknownVariable(Spanned::new(String::from("y"), Span::new(6, 1, 1, 7))))
+        );
+    }
+
+    #[test]
+    fn interpret_programs_keeping_assignments() {
+        let ast: Expr = parse(tokenize("r = 3; area = 3.14159 * r^2; area").unwrap()).unwrap();
+        let mut context: Context<f64> = Context::new();
+
+        assert_eq!(interpret_with(&ast, &mut context), Ok(28.27431));
+        assert_eq!(context.get("r"), Some(&3.0));
+
+        let ast: Expr = parse(tokenize("r = r + 1").unwrap()).unwrap();
+        assert_eq!(interpret_with(&ast, &mut context), Ok(4.0));
+    }
+
+    #[test]
+    fn interpret_user_defined_functions() {
+        let ast: Expr = parse(tokenize("x = 10; f(x, y) = x^2 + y; g(y) = f(y, x); f(3, 4) + g(1)").unwrap()).unwrap();
+        let mut context: Context<i64> = Context::new();
+
+        // `x` within `g` is the variable, within `f` the parameter.
+        assert_eq!(interpret_with(&ast, &mut context), Ok(13 + 11));
+        assert_eq!(context.get("x"), Some(&10));
+        assert_eq!(context.get("y"), None);
+    }
+
+    #[test]
+    fn interpret_rejects_bad_function_calls() {
+        let result: Vec<Result<i64, EvalError>> = ["f(x) = x; f(1, 2)", "f(1)", "f(x) = f(x) + 1; f(1)", "f(x) = x"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<i64, EvalError>> = [
+            Err(EvalError::WrongArgumentCount {
+                function: Spanned::new(String::from("f"), Span::new(10, 1, 1, 11)),
+                expected: 1,
+                found: 2
+            }),
+            Err(EvalError::UnknownFunction(Spanned::new(String::from("f"), Span::new(0, 1, 1, 1)))),
+            Err(EvalError::RecursionLimit(Span::new(7, 4, 1, 8))),
+            Err(EvalError::MissingValue(Span::new(0, 1, 1, 1)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_deep_expressions_without_overflowing_the_stack() {
+        let result: Vec<Result<i64, EvalError>> = [
+            vec!["1"; 1_000].join(" + "),
+            format!("{}1", "-".repeat(999)),
+            format!("x = 0{}", "; x = x + 1".repeat(5_000)),
+            vec!["1"; 3_000].join(" + ")
+        ]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).and_then(|ast| interpret(&ast)))
+            .collect();
+
+        let expected_result: Vec<Result<i64, EvalError>> = [
+            Ok(1_000),
+            Ok(-1),
+            Ok(5_000),
+            Err(EvalError::TooDeep(Span::new(0, 4_001, 1, 1)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_recursions_up_to_the_default_limit() {
+        let result: Vec<Result<f64, EvalError>> = [
+            "f(n) = n < 1 ? 1 : n * f(n - 1); f(100)",
+            "f(n) = n < 1 ? 0 : 1 + f(n - 1); f(255)",
+            "f(n) = n < 1 ? 0 : 1 + f(n - 1); f(256)"
+        ]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<f64, EvalError>> = [
+            Ok((1..=100).fold(1.0, |product, factor| product * factor as f64)),
+            Ok(255.0),
+            Err(EvalError::RecursionLimit(Span::new(23, 8, 1, 24)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_with_a_configurable_recursion_limit() {
+        let ast: Expr = parse(tokenize("f(x) = x + 1; g(x) = f(x) * 2; g(1)").unwrap()).unwrap();
+        let mut context: Context<i64> = Context::new();
+        context.set_max_depth(1);
+
+        assert_eq!(interpret_with(&ast, &mut context), Err(EvalError::RecursionLimit(Span::new(21, 4, 1, 22))));
+
+        context.set_max_depth(2);
+        assert_eq!(interpret_with(&ast, &mut context), Ok(4));
+    }
+
+    #[test]
+    fn interpret_recursions_up_to_the_depth_limit_on_a_main_thread_stack() {
+        let source: String = format!("f(n) = n < 1 ? 0 : 1 + f(n - 1); f({})", MAX_DEPTH_LIMIT - 1);
+        let evaluation = move || {
+            let mut context: Context<i64> = Context::new();
+            context.set_max_depth(usize::MAX);
+
+            interpret_with(&parse(tokenize(&source).unwrap()).unwrap(), &mut context)
+        };
+        let result: Result<i64, EvalError> = ::std::thread::Builder::new()
+            .stack_size(8 << 20)
+            .spawn(evaluation)
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert_eq!(result, Ok(MAX_DEPTH_LIMIT as i64 - 1));
+    }
+
+    #[test]
+    fn interpret_built_in_functions() {
+        let result: Vec<f64> = ["sqrt(16) + abs(-2)", "max(min(1, 2), floor(2.5))", "round(-2.5) * ceil(0.1)", "log10(exp(ln(1000)))"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()).unwrap())
+            .collect();
+
+        let expected_result: Vec<f64> = [6.0, 2.0, -3.0, 3.0].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_built_in_functions_on_every_number_type() {
+        let sqrt: Result<Complex, EvalError> = interpret(&parse(tokenize("sqrt(-1)").unwrap()).unwrap());
+        assert_eq!(sqrt.map(|value| value.to_string()), Ok(String::from("0+1i")));
+
+        let sqrt: Result<i64, EvalError> = interpret(&parse(tokenize("sqrt(49) - sqrt(2)").unwrap()).unwrap());
+        assert_eq!(sqrt, Err(EvalError::Arithmetic(ArithmeticError::NotRepresentable, Span::new(11, 7, 1, 12))));
+
+        let sin: Interval = interpret(&parse(tokenize("sin([0, 2])").unwrap()).unwrap()).unwrap();
+        assert_eq!(sin.upper(), 1.0);
+    }
+
+    #[test]
+    fn interpret_rejects_bad_built_in_calls() {
+        let result: Vec<Result<i64, EvalError>> = ["max(1)", "sqrt(1, 2)", "sqrt()"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<i64, EvalError>> = [
+            Err(EvalError::WrongArgumentCount {
+                function: Spanned::new(String::from("max"), Span::new(0, 3, 1, 1)),
+                expected: 2,
+                found: 1
+            }),
+            Err(EvalError::WrongArgumentCount {
+                function: Spanned::new(String::from("sqrt"), Span::new(0, 4, 1, 1)),
+                expected: 1,
+                found: 2
+            }),
+            Err(EvalError::WrongArgumentCount {
+                function: Spanned::new(String::from("sqrt"), Span::new(0, 4, 1, 1)),
+                expected: 1,
+                found: 0
+            })
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn user_defined_functions_shadow_built_ins() {
+        let result: Result<i64, EvalError> = interpret(&parse(tokenize("abs(x) = 2 * x; abs(-3)").unwrap()).unwrap());
+
+        assert_eq!(result, Ok(-6));
+    }
+
+    #[test]
+    fn interpret_constants_at_the_precision_of_the_number_type() {
+        let pi: f32 = interpret(&parse(tokenize("pi").unwrap()).unwrap()).unwrap();
+        assert_eq!(pi, std::f32::consts::PI);
+
+        let result: Vec<f64> = ["pi", "e", "tau", "phi", "cos(pi)", "e^2"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()).unwrap())
+            .collect();
+
+        let expected_result: Vec<f64> = [
+            std::f64::consts::PI,
+            std::f64::consts::E,
+            std::f64::consts::TAU,
+            (1.0 + 5f64.sqrt()) / 2.0,
+            -1.0,
+            std::f64::consts::E * std::f64::consts::E
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+
+        let mut context: Context<Decimal> = Context::with_settings(DecimalSettings { scale: 30, rounding: Rounding::HalfEven });
+        let e: Result<Decimal, EvalError> = interpret_with(&parse(tokenize("e").unwrap()).unwrap(), &mut context);
+        assert_eq!(e.map(|value| value.to_string()), Ok(String::from("2.718281828459045235360287471353")));
+
+        let pi: Interval = interpret(&parse(tokenize("pi").unwrap()).unwrap()).unwrap();
+        assert_eq!((pi.lower(), pi.upper()), (std::f64::consts::PI, std::f64::consts::PI.next_up()));
+
+        let pi: Result<i64, EvalError> = interpret(&parse(tokenize("2 * pi").unwrap()).unwrap());
+        assert_eq!(pi, Err(EvalError::Arithmetic(ArithmeticError::NotRepresentable, Span::new(4, 2, 1, 5))));
+    }
+
+    #[test]
+    fn interpret_refuses_assignments_to_constants() {
+        let result: Vec<Result<f64, EvalError>> = ["pi = 3", "x = e = 1", "f(phi) = phi; 1"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<f64, EvalError>> = [
+            Err(EvalError::ConstantAssignment(Spanned::new(String::from("pi"), Span::new(0, 2, 1, 1)))),
+            Err(EvalError::ConstantAssignment(Spanned::new(String::from("e"), Span::new(4, 1, 1, 5)))),
+            Err(EvalError::ConstantAssignment(Spanned::new(String::from("phi"), Span::new(2, 3, 1, 3))))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_modulo_integer_division_and_factorial() {
+        let result: Vec<Result<i64, EvalError>> = ["-7 % 3", "7 // -2", "3! + 2^3!", "(-3)!", "21!", "5 % 0"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<i64, EvalError>> = [
+            Ok(2),
+            Ok(-4),
+            Ok(6 + 64),
+            Err(EvalError::Arithmetic(ArithmeticError::OutOfDomain, Span::new(4, 1, 1, 5))),
+            Err(EvalError::Arithmetic(ArithmeticError::Overflow, Span::new(2, 1, 1, 3))),
+            Err(EvalError::Arithmetic(ArithmeticError::DivisionByZero, Span::new(2, 1, 1, 3)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+
+        let factorial: Result<f64, EvalError> = interpret(&parse(tokenize("2.5!").unwrap()).unwrap());
+        assert_eq!(factorial, Err(EvalError::Arithmetic(ArithmeticError::OutOfDomain, Span::new(3, 1, 1, 4))));
+    }
+
+    #[test]
+    fn interpret_comparisons_and_logical_operators() {
+        let result: Vec<Result<Value<i64>, EvalError>> = [
+            "1 < 2", "2 <= 1", "3 > 3", "3 >= 3", "1 + 1 == 2", "1 != 1",
+            "1 < 2 && 2 < 1", "1 < 2 || 2 < 1", "!(1 < 2)", "1 < 2 ? 10 : 20", "f(x) = x > 0; f(-1)"
+        ]
+            .iter()
+            .map(|string| interpret_value(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Value<i64>, EvalError>> = [
+            true, false, false, true, true, false, false, true, false
+        ]
+            .iter()
+            .map(|&boolean| Ok(Value::Boolean(boolean)))
+            .chain([Ok(Value::Number(10)), Ok(Value::Boolean(false))].iter().cloned())
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_bitwise_operators_on_integers() {
+        let result: Vec<Result<f64, EvalError>> = ["flags = 0b1010; flags & 1 << 3", "0xFF xor 0x0F | 0x100", "~0 << 4", "2.5 & 1"]
+            .iter()
+            .map(|string| interpret(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<f64, EvalError>> = [
+            Ok(8.0),
+            Ok(496.0),
+            Ok(-16.0),
+            Err(EvalError::Arithmetic(ArithmeticError::OutOfDomain, Span::new(4, 1, 1, 5)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_chained_comparisons() {
+        let result: Vec<Result<Value<i64>, EvalError>> = ["x = 5; 0 <= x < 10", "x = 15; 0 <= x < 10", "1 < 2 < 3 > 4", "x = 2; 1 < x < 3 < x"]
+            .iter()
+            .map(|string| interpret_value(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Value<i64>, EvalError>> = [true, false, false, false]
+            .iter()
+            .map(|&boolean| Ok(Value::Boolean(boolean)))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_approximate_equality_with_the_tolerance_of_the_context() {
+        let mut context: Context<f64> = Context::new();
+        let mut result: Vec<Result<Value<f64>, EvalError>> = ["0.1 + 0.2 == 0.3", "0.1 + 0.2 ~= 0.3", "sin(pi) ≈ 0", "1 ~= 1.001"]
+            .iter()
+            .map(|string| interpret_value_with(&parse(tokenize(string).unwrap()).unwrap(), &mut context))
+            .collect();
+
+        context.set_tolerance(Tolerance { relative: 1e-2, absolute: 0.0 });
+        result.push(interpret_value_with(&parse(tokenize("1 ~= 1.001").unwrap()).unwrap(), &mut context));
+
+        let expected_result: Vec<Result<Value<f64>, EvalError>> = [false, true, true, false, true]
+            .iter()
+            .map(|&boolean| Ok(Value::Boolean(boolean)))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_logical_operators_and_conditionals_lazily() {
+        let result: Vec<Result<Value<i64>, EvalError>> = ["1 > 2 && 1 / 0 > 0", "1 < 2 || x", "1 < 2 ? 3 : 1 / 0", "1 > 2 ? y : 4"]
+            .iter()
+            .map(|string| interpret_value(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Value<i64>, EvalError>> = [
+            Ok(Value::Boolean(false)),
+            Ok(Value::Boolean(true)),
+            Ok(Value::Number(3)),
+            Ok(Value::Number(4))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_keeps_booleans_and_numbers_apart() {
+        let result: Vec<Result<Value<i64>, EvalError>> = ["1 + (2 < 3)", "1 ? 2 : 3", "!1", "1 && 2 < 3", "x = 1 < 2", "(1 < 2) < 3"]
+            .iter()
+            .map(|string| interpret_value(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Value<i64>, EvalError>> = [
+            Err(EvalError::ExpectedNumber(Span::new(5, 5, 1, 6))),
+            Err(EvalError::ExpectedBoolean(Span::new(0, 1, 1, 1))),
+            Err(EvalError::ExpectedBoolean(Span::new(1, 1, 1, 2))),
+            Err(EvalError::ExpectedBoolean(Span::new(0, 1, 1, 1))),
+            Err(EvalError::ExpectedNumber(Span::new(4, 5, 1, 5))),
+            Err(EvalError::ExpectedNumber(Span::new(1, 5, 1, 2)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+
+        // Evaluating `f(n - 1)` twice per call would take 2^50 calls.
+        let mut context: Context<i64> = Context::new();
+        let result: Result<Value<i64>, EvalError> = parse(tokenize("f(n) = n == 0 ? 0 : 0 <= f(n - 1) < n ? n : -1; f(50)").unwrap())
+            .and_then(|ast| interpret_value_with(&ast, &mut context));
+        assert_eq!(result, Ok(Value::Number(50)));
+    }
+
+    #[test]
+    fn interpret_equality_of_booleans() {
+        let result: Vec<Result<Value<i64>, EvalError>> = [
+            "(1 < 2) == (2 < 3)",
+            "x = -1; y = 2; x > 0 == y > 0",
+            "(1 < 2) != (2 > 3)",
+            "(1 < 2) == 1",
+            "1 == (1 < 2)",
+            "(1 < 2) ~= (1 < 2)"
+        ]
+            .iter()
+            .map(|string| interpret_value(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Value<i64>, EvalError>> = [
+            Ok(Value::Boolean(true)),
+            Ok(Value::Boolean(false)),
+            Ok(Value::Boolean(true)),
+            Err(EvalError::ExpectedBoolean(Span::new(11, 1, 1, 12))),
+            Err(EvalError::ExpectedNumber(Span::new(6, 5, 1, 7))),
+            Err(EvalError::ExpectedNumber(Span::new(1, 5, 1, 2)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_comparisons_of_unordered_values() {
+        let complex: Result<Value<Complex>, EvalError> = interpret_value(&parse(tokenize("i < 1").unwrap()).unwrap());
+        assert_eq!(complex, Err(EvalError::Arithmetic(ArithmeticError::Incomparable, Span::new(2, 1, 1, 3))));
+
+        let complex: Result<Value<Complex>, EvalError> = interpret_value(&parse(tokenize("i * i == -1").unwrap()).unwrap());
+        assert_eq!(complex, Ok(Value::Boolean(true)));
+
+        let result: Vec<Result<Value<Interval>, EvalError>> = ["[1, 2] < [3, 4]", "[1, 3] < [2, 4]", "[1, 2] == [3, 4]"]
+            .iter()
+            .map(|string| interpret_value(&parse(tokenize(string).unwrap()).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Value<Interval>, EvalError>> = [
+            Ok(Value::Boolean(true)),
+            Err(EvalError::Arithmetic(ArithmeticError::Incomparable, Span::new(7, 1, 1, 8))),
+            Ok(Value::Boolean(false))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn interpret_rejects_an_invalid_literal() {
+        let literal: Spanned<Literal> = Spanned::new(Literal::new(Vec::new()), Span::default());
+        let result: Result<f32, EvalError> = interpret(&Expr::Number(literal));
+
+        assert_eq!(result, Err(EvalError::Arithmetic(ArithmeticError::NotRepresentable, Span::default())));
+    }
 }
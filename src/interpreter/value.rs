@@ -0,0 +1,69 @@
+use std::fmt::{Display, Formatter, Error};
+
+use error::EvalError;
+use number::Number;
+use tokenizer::span::Span;
+
+// Result of an expression: a number, or a boolean for comparisons and
+// logical operators. Neither converts implicitly into the other.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<N: Number> {
+    Number(N),
+    Boolean(bool)
+}
+
+impl<N: Number> Value<N> {
+    // The number held, or an error pointing at `span`, where the value comes
+    // from.
+    pub fn number(self, span: Span) -> Result<N, EvalError> {
+        match self {
+            Value::Number(number) => Ok(number),
+            Value::Boolean(_) => Err(EvalError::ExpectedNumber(span))
+        }
+    }
+
+    pub fn boolean(self, span: Span) -> Result<bool, EvalError> {
+        match self {
+            Value::Boolean(boolean) => Ok(boolean),
+            Value::Number(_) => Err(EvalError::ExpectedBoolean(span))
+        }
+    }
+}
+
+impl<N: Number> Display for Value<N> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Value::Number(ref number) => write!(f, "{}", number),
+            Value::Boolean(boolean) => write!(f, "{}", boolean)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_are_displayed_as_numbers_or_booleans() {
+        let result: Vec<String> = [Value::Number(1.5), Value::Boolean(true), Value::Boolean(false)]
+            .iter()
+            .map(|value: &Value<f64>| value.to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1.5", "true", "false"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn numbers_and_booleans_do_not_convert_into_each_other() {
+        let span: Span = Span::new(0, 1, 1, 1);
+
+        assert_eq!(Value::Number(1i64).number(span), Ok(1));
+        assert_eq!(Value::Boolean::<i64>(true).number(span), Err(EvalError::ExpectedNumber(span)));
+        assert_eq!(Value::Number(1i64).boolean(span), Err(EvalError::ExpectedBoolean(span)));
+    }
+}
@@ -0,0 +1,127 @@
+pub mod error;
+pub mod interpreter;
+pub mod number;
+pub mod parser;
+pub mod tokenizer;
+
+pub use error::EvalError;
+pub use interpreter::{interpret, interpret_value, interpret_value_with, interpret_with};
+pub use interpreter::context::Context;
+pub use interpreter::value::Value;
+pub use number::{ArithmeticError, Number};
+pub use parser::expr::Expr;
+pub use parser::parse;
+pub use tokenizer::span::{Span, Spanned};
+pub use tokenizer::{tokenize, tokenize_with, TokenizerOptions};
+
+// Runs the whole pipeline (tokenizer, parser, interpreter) on `expression`,
+// computing with `f64`.
+pub fn evaluate(expression: &str) -> Result<f64, EvalError> {
+    evaluate_as(expression)
+}
+
+// Same as `evaluate`, computing with any `Number` type.
+pub fn evaluate_as<N: Number>(expression: &str) -> Result<N, EvalError> {
+    evaluate_with(expression, &mut Context::new())
+}
+
+// Same as `evaluate_as`, resolving variables and taking the number type
+// settings from `context`, where assignments are kept.
+pub fn evaluate_with<N: Number>(expression: &str, context: &mut Context<N>) -> Result<N, EvalError> {
+    let tokens = tokenize(expression)?;
+    let ast = parse(tokens)?;
+
+    interpret_with(&ast, context)
+}
+
+// Same as `evaluate_with`, for expressions which may also give a boolean,
+// such as comparisons.
+pub fn evaluate_value_with<N: Number>(expression: &str, context: &mut Context<N>) -> Result<Value<N>, EvalError> {
+    let tokens = tokenize(expression)?;
+    let ast = parse(tokens)?;
+
+    interpret_value_with(&ast, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use number::big_integer::BigInteger;
+
+    #[test]
+    fn evaluate_runs_the_whole_pipeline() {
+        let result: f64 = evaluate("(2+2)*3^2/3").unwrap();
+
+        let expected_result: f64 = 12.0;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn evaluate_reports_errors_instead_of_panicking() {
+        let result: Vec<Result<f64, EvalError>> = ["", "3+", ")("]
+            .iter()
+            .map(|string| evaluate(string))
+            .collect();
+
+        let expected_result: Vec<Result<f64, EvalError>> = [
+            Err(EvalError::MissingOperand(Span::default())),
+            Err(EvalError::MissingOperand(Span::new(1, 1, 1, 2))),
+            Err(EvalError::MismatchedParenthesis(Span::new(0, 1, 1, 1)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn evaluate_with_resolves_variables_from_the_context() {
+        let mut context: Context<i64> = Context::new();
+        let result: Vec<i64> = [1, 2, 3]
+            .iter()
+            .map(|&x| {
+                context.set("x", x);
+                evaluate_with("x^2 + 1", &mut context).unwrap()
+            })
+            .collect();
+
+        assert_eq!(result, [2, 5, 10]);
+    }
+
+    #[test]
+    fn evaluate_value_with_gives_numbers_or_booleans() {
+        let mut context: Context<i64> = Context::new();
+        let result: Vec<Result<Value<i64>, EvalError>> = ["1 < 2", "1 < 2 ? 10 : 20"]
+            .iter()
+            .map(|string| evaluate_value_with(string, &mut context))
+            .collect();
+
+        let expected_result: Vec<Result<Value<i64>, EvalError>> = [Ok(Value::Boolean(true)), Ok(Value::Number(10))].to_vec();
+
+        assert_eq!(result, expected_result);
+        assert_eq!(evaluate_with("1 < 2", &mut context), Err(EvalError::ExpectedNumber(Span::new(0, 5, 1, 1))));
+    }
+
+    #[test]
+    fn evaluate_as_computes_with_the_requested_number_type() {
+        let result: i64 = evaluate_as("7/2").unwrap();
+
+        let expected_result: i64 = 3;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn evaluate_as_big_integers_keeps_every_digit() {
+        let result: Vec<String> = ["2^100", "99999999999999999999+1"]
+            .iter()
+            .map(|string| evaluate_as::<BigInteger>(string).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1267650600228229401496703205376", "100000000000000000000"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+}
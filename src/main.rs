@@ -1,21 +1,146 @@
+extern crate math_eval;
+
 use std::env;
+use std::process;
+
+use math_eval::{interpret_value_with, parse, tokenize_with, Context, EvalError, Number, TokenizerOptions, Value};
+use math_eval::interpreter::context::MAX_DEPTH_LIMIT;
+use math_eval::number::big_integer::{BigInteger, MAX_DIGITS};
+use math_eval::number::complex::Complex;
+use math_eval::number::decimal::{Decimal, DecimalSettings, Rounding};
+use math_eval::number::interval::Interval;
+use math_eval::number::rational::Rational;
+use math_eval::number::tolerance::Tolerance;
+use math_eval::number::uncertain::Uncertain;
+
+const USAGE: &str = "usage: math_eval [--locale-separators] [--mode <mode>] [--mixed]
+                 [--scale <digits>] [--rounding half-even|half-up|truncate]
+                 [--relative-tolerance <value>] [--absolute-tolerance <value>]
+                 [--max-depth <calls>] [--] <string to evaluate>
+
+modes: float (default), integer, big-integer, rational, decimal, complex,
+       interval, uncertain";
+
+// Prints the error followed by the offending line of the expression, with
+// the faulty part underlined.
+fn report(expression: &str, error: &EvalError) {
+    let span = error.span();
+    let line: &str = expression.lines().nth(span.line - 1).unwrap_or("");
+    let width: usize = expression
+        .get(span.offset..span.end())
+        .map_or(0, |string| string.chars().count())
+        .max(1);
+
+    eprintln!("error: {}", error);
+    eprintln!("  {}", line);
+    eprintln!("  {}{}", " ".repeat(span.column - 1), "^".repeat(width));
+}
 
-mod interpreter;
-mod parser;
-mod tokenizer;
+// Arguments such as `--1` or `-x` are expressions.
+fn is_flag(arg: &str) -> bool {
+    arg.starts_with("--") && arg[2..].starts_with(|character: char| character.is_ascii_alphabetic())
+}
+
+fn usage_error() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
 
-use interpreter::interpret;
-use parser::parse;
-use tokenizer::tokenize;
-use tokenizer::token::Token;
+// Non negative number following a flag.
+fn tolerance_arg(arg: Option<String>) -> f64 {
+    arg.and_then(|value| value.parse().ok())
+        .filter(|value: &f64| *value >= 0.0)
+        .unwrap_or_else(|| usage_error())
+}
+
+fn run<N: Number>(
+    expression: &str,
+    options: &TokenizerOptions,
+    settings: &N::Settings,
+    tolerance: Tolerance,
+    max_depth: Option<usize>,
+    format: fn(&N) -> String
+) {
+    let mut context: Context<N> = Context::with_settings(settings.clone());
+    context.set_tolerance(tolerance);
+    if let Some(max_depth) = max_depth {
+        context.set_max_depth(max_depth);
+    }
+    let result: Result<Value<N>, EvalError> = tokenize_with(expression, options)
+        .and_then(parse)
+        .and_then(|ast| interpret_value_with(&ast, &mut context));
+
+    match result {
+        Ok(Value::Number(result)) => println!("{}", format(&result)),
+        Ok(Value::Boolean(result)) => println!("{}", result),
+        Err(error) => {
+            report(expression, &error);
+            process::exit(1);
+        }
+    }
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let string = &args[1];
+    let mut options: TokenizerOptions = TokenizerOptions::default();
+    let mut mode: String = String::from("float");
+    let mut mixed: bool = false;
+    let mut decimal_settings: DecimalSettings = DecimalSettings::default();
+    let mut tolerance: Tolerance = Tolerance::default();
+    let mut max_depth: Option<usize> = None;
+    let mut expression: Option<String> = None;
+    let mut args = env::args().skip(1);
 
-    let tokens: Vec<Token> = tokenize(string);
-    let ast: Vec<Token> = parse(tokens);
-    let result: f32 = interpret(ast);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--locale-separators" => options.locale_separators = true,
+            "--mode" => mode = args.next().unwrap_or_else(|| usage_error()),
+            "--mixed" => mixed = true,
+            "--scale" => {
+                decimal_settings.scale = args.next()
+                    .and_then(|scale| scale.parse().ok())
+                    .filter(|scale: &u32| *scale as usize <= MAX_DIGITS)
+                    .unwrap_or_else(|| usage_error());
+            },
+            "--rounding" => {
+                decimal_settings.rounding = match args.next().as_deref() {
+                    Some("half-even") => Rounding::HalfEven,
+                    Some("half-up") => Rounding::HalfUp,
+                    Some("truncate") => Rounding::Truncate,
+                    _ => usage_error()
+                };
+            },
+            "--relative-tolerance" => tolerance.relative = tolerance_arg(args.next()),
+            "--absolute-tolerance" => tolerance.absolute = tolerance_arg(args.next()),
+            "--max-depth" => {
+                max_depth = Some(
+                    args.next()
+                        .and_then(|depth| depth.parse().ok())
+                        .filter(|depth: &usize| *depth <= MAX_DEPTH_LIMIT)
+                        .unwrap_or_else(|| usage_error())
+                );
+            },
+            // Whatever follows is the expression, even if it looks like a flag.
+            "--" if expression.is_none() => {
+                expression = args.next();
+                if expression.is_none() || args.next().is_some() { usage_error(); }
+            },
+            flag if flag == "--" || is_flag(flag) => usage_error(),
+            _ if expression.is_some() => usage_error(),
+            _ => expression = Some(arg)
+        }
+    }
+    let expression: String = expression.unwrap_or_else(|| usage_error());
 
-    println!("{:?}", result);
+    match mode.as_str() {
+        "float" => run::<f64>(&expression, &options, &(), tolerance, max_depth, f64::to_string),
+        "integer" => run::<i64>(&expression, &options, &(), tolerance, max_depth, i64::to_string),
+        "big-integer" => run::<BigInteger>(&expression, &options, &(), tolerance, max_depth, BigInteger::to_string),
+        "rational" if mixed => run::<Rational>(&expression, &options, &(), tolerance, max_depth, |value| value.mixed().to_string()),
+        "rational" => run::<Rational>(&expression, &options, &(), tolerance, max_depth, Rational::to_string),
+        "decimal" => run::<Decimal>(&expression, &options, &decimal_settings, tolerance, max_depth, Decimal::to_string),
+        "complex" => run::<Complex>(&expression, &options, &(), tolerance, max_depth, Complex::to_string),
+        "interval" => run::<Interval>(&expression, &options, &(), tolerance, max_depth, Interval::to_string),
+        "uncertain" => run::<Uncertain>(&expression, &options, &(), tolerance, max_depth, Uncertain::to_string),
+        _ => usage_error()
+    }
 }
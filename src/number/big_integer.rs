@@ -0,0 +1,707 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Error};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{integer_digits, ArithmeticError, Number};
+use super::tolerance::Tolerance;
+use tokenizer::token::literal::Literal;
+use tokenizer::token::literal::digit::Digit;
+use tokenizer::token::literal::radix::Radix;
+
+// Most digits of a power, shift, factorial or literal. Storing one digit per
+// element, larger ones would take too long to compute, if they fit in memory
+// at all.
+pub const MAX_DIGITS: usize = 10_000;
+
+// Largest operand of the factorial, whose result has 9998 digits.
+const MAX_FACTORIAL: u64 = 3_248;
+
+// Unbounded integer made of decimal digits, stored least significant first
+// and without leading zeros: zero has no digit at all.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigInteger {
+    negative: bool,
+    digits: Vec<Digit>
+}
+
+fn digit(value: u32) -> Digit {
+    Digit::from_value(value).unwrap()
+}
+
+fn trim(mut digits: Vec<Digit>) -> Vec<Digit> {
+    while digits.last() == Some(&Digit::Zero) {
+        digits.pop();
+    }
+    digits
+}
+
+fn compare_magnitudes(first: &[Digit], second: &[Digit]) -> Ordering {
+    first.len()
+        .cmp(&second.len())
+        .then_with(|| {
+            first.iter()
+                .rev()
+                .map(Digit::value)
+                .cmp(second.iter().rev().map(Digit::value))
+        })
+}
+
+fn add_magnitudes(first: &[Digit], second: &[Digit]) -> Vec<Digit> {
+    let mut digits: Vec<Digit> = Vec::new();
+    let mut carry: u32 = 0;
+
+    for index in 0..first.len().max(second.len()) {
+        let sum: u32 = carry
+            + first.get(index).map_or(0, Digit::value)
+            + second.get(index).map_or(0, Digit::value);
+        digits.push(digit(sum % 10));
+        carry = sum / 10;
+    }
+    if carry > 0 { digits.push(digit(carry)); }
+
+    digits
+}
+
+// `first` has to be the largest of both.
+fn subtract_magnitudes(first: &[Digit], second: &[Digit]) -> Vec<Digit> {
+    let mut digits: Vec<Digit> = Vec::new();
+    let mut borrow: u32 = 0;
+
+    for (index, first_digit) in first.iter().enumerate() {
+        let subtrahend: u32 = borrow + second.get(index).map_or(0, Digit::value);
+        let minuend: u32 = first_digit.value();
+
+        if minuend >= subtrahend {
+            digits.push(digit(minuend - subtrahend));
+            borrow = 0;
+        } else {
+            digits.push(digit(minuend + 10 - subtrahend));
+            borrow = 1;
+        }
+    }
+
+    trim(digits)
+}
+
+fn multiply_magnitudes(first: &[Digit], second: &[Digit]) -> Vec<Digit> {
+    let mut values: Vec<u32> = vec![0; first.len() + second.len()];
+
+    for (i, first_digit) in first.iter().enumerate() {
+        let mut carry: u32 = 0;
+
+        for (j, second_digit) in second.iter().enumerate() {
+            let product: u32 = values[i + j] + first_digit.value() * second_digit.value() + carry;
+            values[i + j] = product % 10;
+            carry = product / 10;
+        }
+        values[i + second.len()] += carry;
+    }
+
+    trim(values.into_iter().map(digit).collect())
+}
+
+// Long division of magnitudes, `second` being non zero.
+fn divide_magnitudes(first: &[Digit], second: &[Digit]) -> (Vec<Digit>, Vec<Digit>) {
+    let mut quotient: Vec<Digit> = Vec::new();
+    let mut remainder: Vec<Digit> = Vec::new();
+
+    for first_digit in first.iter().rev() {
+        remainder.insert(0, first_digit.clone());
+        remainder = trim(remainder);
+
+        let mut quotient_digit: u32 = 0;
+        while compare_magnitudes(&remainder, second) != Ordering::Less {
+            remainder = subtract_magnitudes(&remainder, second);
+            quotient_digit += 1;
+        }
+        quotient.insert(0, digit(quotient_digit));
+    }
+
+    (trim(quotient), remainder)
+}
+
+// Bitwise combination of the two's complement representations of `first`
+// and `second`, which go on forever for negative numbers. Bits are taken
+// from the lowest one until both numbers are 0 or -1, all of whose
+// remaining bits are the same.
+fn bitwise(first: &BigInteger, second: &BigInteger, operation: fn(bool, bool) -> bool) -> BigInteger {
+    let two: BigInteger = BigInteger::from(2);
+    let minus_one: BigInteger = BigInteger::from(-1);
+    // Halves rounding down, giving the bit shifted out.
+    let halve = |value: &BigInteger| -> (BigInteger, bool) {
+        let (quotient, remainder) = value.div_rem(&two).unwrap();
+
+        if remainder.is_negative() { (&quotient - &BigInteger::from(1), true) } else { (quotient, !remainder.is_zero()) }
+    };
+    let (mut first, mut second): (BigInteger, BigInteger) = (first.clone(), second.clone());
+    let mut result: BigInteger = BigInteger::zero();
+    let mut bit: BigInteger = BigInteger::from(1);
+
+    while !((first.is_zero() || first == minus_one) && (second.is_zero() || second == minus_one)) {
+        let (first_rest, first_bit) = halve(&first);
+        let (second_rest, second_bit) = halve(&second);
+
+        if operation(first_bit, second_bit) { result = &result + &bit; }
+        first = first_rest;
+        second = second_rest;
+        bit = &bit * &two;
+    }
+
+    if operation(first.is_negative(), second.is_negative()) { &result - &bit } else { result }
+}
+
+impl BigInteger {
+    fn new(negative: bool, digits: Vec<Digit>) -> BigInteger {
+        let digits: Vec<Digit> = trim(digits);
+
+        BigInteger { negative: negative && !digits.is_empty(), digits }
+    }
+
+    pub fn zero() -> BigInteger {
+        BigInteger::new(false, Vec::new())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.digits.first().map_or(0, Digit::value).is_multiple_of(2)
+    }
+
+    pub fn abs(&self) -> BigInteger {
+        BigInteger::new(false, self.digits.clone())
+    }
+
+    // Digits from the most significant one, as they are written.
+    pub fn digits(&self) -> Vec<Digit> {
+        self.digits.iter().rev().cloned().collect()
+    }
+
+    pub fn from_digits(negative: bool, digits: &[Digit]) -> BigInteger {
+        BigInteger::new(negative, digits.iter().rev().cloned().collect())
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.negative { return None; }
+
+        self.digits.iter().rev().try_fold(0u64, |value, digit| {
+            value.checked_mul(10).and_then(|value| value.checked_add(digit.value() as u64))
+        })
+    }
+
+    // Quotient truncated toward zero and remainder of the same sign as
+    // `self`, such that `self = quotient * other + remainder`.
+    pub fn div_rem(&self, other: &BigInteger) -> Result<(BigInteger, BigInteger), ArithmeticError> {
+        if other.is_zero() { return Err(ArithmeticError::DivisionByZero); }
+
+        let (quotient, remainder) = divide_magnitudes(&self.digits, &other.digits);
+
+        Ok((
+            BigInteger::new(self.negative != other.negative, quotient),
+            BigInteger::new(self.negative, remainder)
+        ))
+    }
+
+    pub fn pow(&self, mut exponent: u64) -> BigInteger {
+        let mut base: BigInteger = self.clone();
+        let mut result: BigInteger = BigInteger::from(1);
+
+        while exponent > 0 {
+            if exponent % 2 == 1 { result = &result * &base; }
+            base = &base * &base;
+            exponent /= 2;
+        }
+
+        result
+    }
+
+    // Same as `pow`, failing with `Overflow` rather than computing a power of
+    // more than `MAX_DIGITS` digits. It has `exponent * log10(|self|)` of
+    // them, the logarithm being taken on the leading digits.
+    pub fn checked_pow(&self, exponent: u64) -> Result<BigInteger, ArithmeticError> {
+        let leading: f64 = self.digits
+            .iter()
+            .rev()
+            .take(15)
+            .fold(0.0, |value, digit| value * 10.0 + digit.value() as f64);
+        let logarithm: f64 = leading.log10() + self.digits.len().saturating_sub(15) as f64;
+        if exponent as f64 * logarithm >= MAX_DIGITS as f64 { return Err(ArithmeticError::Overflow); }
+
+        Ok(self.pow(exponent))
+    }
+
+    // `self`, unless it has more than `MAX_DIGITS` digits.
+    fn limited(self) -> Result<BigInteger, ArithmeticError> {
+        if self.digits.len() > MAX_DIGITS { return Err(ArithmeticError::Overflow); }
+
+        Ok(self)
+    }
+
+    // Largest integer whose square is at most `self`, found with Newton's
+    // method. `None` for negative numbers.
+    pub fn integer_sqrt(&self) -> Option<BigInteger> {
+        if self.is_negative() { return None; }
+        if self.is_zero() { return Some(BigInteger::zero()); }
+
+        let two: BigInteger = BigInteger::from(2);
+        let next = |root: &BigInteger| (root + &self.div_rem(root).unwrap().0).div_rem(&two).unwrap().0;
+        let mut root: BigInteger = self.clone();
+        let mut candidate: BigInteger = next(&root);
+
+        while candidate < root {
+            root = candidate;
+            candidate = next(&root);
+        }
+
+        Some(root)
+    }
+}
+
+impl Add for &BigInteger {
+    type Output = BigInteger;
+
+    fn add(self, other: &BigInteger) -> BigInteger {
+        if self.negative == other.negative {
+            return BigInteger::new(self.negative, add_magnitudes(&self.digits, &other.digits));
+        }
+
+        match compare_magnitudes(&self.digits, &other.digits) {
+            Ordering::Less => BigInteger::new(other.negative, subtract_magnitudes(&other.digits, &self.digits)),
+            _ => BigInteger::new(self.negative, subtract_magnitudes(&self.digits, &other.digits))
+        }
+    }
+}
+
+impl Neg for &BigInteger {
+    type Output = BigInteger;
+
+    fn neg(self) -> BigInteger {
+        BigInteger::new(!self.negative, self.digits.clone())
+    }
+}
+
+impl Sub for &BigInteger {
+    type Output = BigInteger;
+
+    fn sub(self, other: &BigInteger) -> BigInteger {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInteger {
+    type Output = BigInteger;
+
+    fn mul(self, other: &BigInteger) -> BigInteger {
+        BigInteger::new(self.negative != other.negative, multiply_magnitudes(&self.digits, &other.digits))
+    }
+}
+
+impl From<i64> for BigInteger {
+    fn from(value: i64) -> BigInteger {
+        let digits: Vec<Digit> = value
+            .unsigned_abs()
+            .to_string()
+            .chars()
+            .rev()
+            .filter_map(Digit::new)
+            .collect();
+
+        BigInteger::new(value < 0, digits)
+    }
+}
+
+impl Ord for BigInteger {
+    fn cmp(&self, other: &BigInteger) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.digits, &other.digits),
+            (true, true) => compare_magnitudes(&other.digits, &self.digits)
+        }
+    }
+}
+
+impl PartialOrd for BigInteger {
+    fn partial_cmp(&self, other: &BigInteger) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInteger {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if self.is_zero() { return write!(f, "0"); }
+
+        let string: String = self.digits.iter().rev().map(Digit::to_char).collect();
+        write!(f, "{}{}", if self.negative { "-" } else { "" }, string)
+    }
+}
+
+impl Number for BigInteger {
+    type Settings = ();
+
+    fn from_literal(literal: &Literal, _settings: &()) -> Result<BigInteger, ArithmeticError> {
+        if literal.is_imaginary() {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+        if literal.radix() != Radix::Decimal {
+            let base: BigInteger = BigInteger::from(literal.radix().base() as i64);
+
+            return Ok(
+                literal.integer()
+                    .iter()
+                    .fold(BigInteger::zero(), |value, digit| &(&value * &base) + &BigInteger::from(digit.value() as i64))
+            );
+        }
+
+        let (digits, power): (Vec<Digit>, u64) = integer_digits(literal)?;
+        (&BigInteger::from_digits(false, &digits) * &BigInteger::from(10).checked_pow(power)?).limited()
+    }
+
+    fn plus(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(&self + &other)
+    }
+
+    fn minus(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(&self - &other)
+    }
+
+    fn times(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(&self * &other)
+    }
+
+    // Truncates toward zero, `div_rem` also gives the remainder.
+    fn divide(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        self.div_rem(&other).map(|(quotient, _)| quotient)
+    }
+
+    fn power(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        let one: BigInteger = BigInteger::from(1);
+
+        if self.abs() == one {
+            let negative: bool = self.negative && !other.is_even();
+            return Ok(BigInteger::new(negative, one.digits));
+        }
+        if other.is_negative() {
+            return Err(if self.is_zero() { ArithmeticError::DivisionByZero } else { ArithmeticError::NotRepresentable });
+        }
+        if self.is_zero() { return Ok(if other.is_zero() { one } else { self }); }
+
+        let exponent: u64 = other.to_u64().ok_or(ArithmeticError::Overflow)?;
+        self.checked_pow(exponent)
+    }
+
+    fn negate(self) -> Result<BigInteger, ArithmeticError> {
+        Ok(-&self)
+    }
+
+    fn compare(&self, other: &BigInteger) -> Result<Ordering, ArithmeticError> {
+        Ok(self.cmp(other))
+    }
+
+    // Integers are exact, so that only equal ones are close enough.
+    fn approximately_equals(&self, other: &BigInteger, _tolerance: &Tolerance, _settings: &()) -> Result<bool, ArithmeticError> {
+        self.equals(other)
+    }
+
+    fn integer_divide(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        let (quotient, remainder) = self.div_rem(&other)?;
+
+        if !remainder.is_zero() && remainder.is_negative() != other.is_negative() {
+            Ok(&quotient - &BigInteger::from(1))
+        } else {
+            Ok(quotient)
+        }
+    }
+
+    fn remainder(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        let (_, remainder) = self.div_rem(&other)?;
+
+        if !remainder.is_zero() && remainder.is_negative() != other.is_negative() {
+            Ok(&remainder + &other)
+        } else {
+            Ok(remainder)
+        }
+    }
+
+    fn factorial(self) -> Result<BigInteger, ArithmeticError> {
+        if self.is_negative() { return Err(ArithmeticError::OutOfDomain); }
+
+        let count: u64 = self.to_u64()
+            .filter(|count| *count <= MAX_FACTORIAL)
+            .ok_or(ArithmeticError::Overflow)?;
+        Ok((2..=count).fold(BigInteger::from(1), |product, factor| &product * &BigInteger::from(factor as i64)))
+    }
+
+    fn bit_and(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(bitwise(&self, &other, |first, second| first && second))
+    }
+
+    fn bit_or(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(bitwise(&self, &other, |first, second| first || second))
+    }
+
+    fn bit_xor(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(bitwise(&self, &other, |first, second| first != second))
+    }
+
+    fn bit_not(self) -> Result<BigInteger, ArithmeticError> {
+        Ok(&(-&self) - &BigInteger::from(1))
+    }
+
+    fn shift_left(self, amount: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        if amount.is_negative() { return Err(ArithmeticError::OutOfDomain); }
+
+        if self.is_zero() { return Ok(self); }
+
+        let amount: u64 = amount.to_u64().ok_or(ArithmeticError::Overflow)?;
+        (&self * &BigInteger::from(2).checked_pow(amount)?).limited()
+    }
+
+    // Shifting by at least 4 bits per digit leaves nothing but the sign, as
+    // `|self| < 10^n <= 2^(4n)`.
+    fn shift_right(self, amount: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        if amount.is_negative() { return Err(ArithmeticError::OutOfDomain); }
+
+        match amount.to_u64() {
+            Some(amount) if amount < 4 * self.digits.len() as u64 => {
+                self.integer_divide(BigInteger::from(2).pow(amount))
+            },
+            _ => Ok(BigInteger::from(if self.is_negative() { -1 } else { 0 }))
+        }
+    }
+
+    // Only perfect squares have an integer root.
+    fn sqrt(self) -> Result<BigInteger, ArithmeticError> {
+        match self.integer_sqrt() {
+            Some(root) if &root * &root == self => Ok(root),
+            _ => Err(ArithmeticError::NotRepresentable)
+        }
+    }
+
+    fn absolute_value(self) -> Result<BigInteger, ArithmeticError> {
+        Ok(self.abs())
+    }
+
+    fn floor(self) -> Result<BigInteger, ArithmeticError> {
+        Ok(self)
+    }
+
+    fn ceil(self) -> Result<BigInteger, ArithmeticError> {
+        Ok(self)
+    }
+
+    fn round(self) -> Result<BigInteger, ArithmeticError> {
+        Ok(self)
+    }
+
+    fn minimum(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(Ord::min(self, other))
+    }
+
+    fn maximum(self, other: BigInteger) -> Result<BigInteger, ArithmeticError> {
+        Ok(Ord::max(self, other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big_integer(string: &str) -> BigInteger {
+        BigInteger::from_literal(&string.parse().unwrap(), &()).unwrap()
+    }
+
+    #[test]
+    fn big_integers_are_represented_with_all_their_digits() {
+        let result: Vec<String> = [
+            "0", "007", "99999999999999999999999", "0xFFFFFFFFFFFFFFFFFFFF", "12e30", "1.5e1", "100e-2", "0.0e-5"
+        ]
+            .iter()
+            .map(|string| big_integer(string).to_string())
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "0",
+            "7",
+            "99999999999999999999999",
+            "1208925819614629174706175",
+            "12000000000000000000000000000000",
+            "15",
+            "1",
+            "0"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn big_integers_add_and_subtract_with_signs() {
+        let result: Vec<String> = [
+            (big_integer("99999999999999999999").plus(big_integer("1"))),
+            (big_integer("1").minus(big_integer("100000000000000000000"))),
+            (BigInteger::from(-5).plus(BigInteger::from(3))),
+            (BigInteger::from(-5).minus(BigInteger::from(-5)))
+        ]
+            .iter()
+            .map(|result| result.clone().unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["100000000000000000000", "-99999999999999999999", "-2", "0"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn big_integers_multiply_and_raise_to_a_power() {
+        let result: Vec<String> = [
+            big_integer("123456789123456789").times(BigInteger::from(-987654321)),
+            BigInteger::from(2).power(BigInteger::from(100)),
+            BigInteger::from(-1).power(BigInteger::from(-3)),
+            BigInteger::from(-3).power(BigInteger::from(3))
+        ]
+            .iter()
+            .map(|result| result.clone().unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "-121932631234567900112635269",
+            "1267650600228229401496703205376",
+            "-1",
+            "-27"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn big_integers_divide_with_remainder() {
+        let result: Vec<(String, String)> = [
+            (big_integer("1000000000000000000007"), BigInteger::from(10)),
+            (BigInteger::from(-7), BigInteger::from(2)),
+            (BigInteger::from(7), BigInteger::from(-2)),
+            (BigInteger::from(3), BigInteger::from(5))
+        ]
+            .iter()
+            .map(|(first, second)| {
+                let (quotient, remainder) = first.div_rem(second).unwrap();
+                (quotient.to_string(), remainder.to_string())
+            })
+            .collect();
+
+        let expected_result: Vec<(String, String)> = [
+            ("100000000000000000000", "7"),
+            ("-3", "-1"),
+            ("-3", "1"),
+            ("0", "3")
+        ]
+            .iter()
+            .map(|&(quotient, remainder)| (String::from(quotient), String::from(remainder)))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn big_integers_reject_what_integers_cannot_hold() {
+        let result: Vec<Result<BigInteger, ArithmeticError>> = [
+            BigInteger::from(1).divide(BigInteger::zero()),
+            BigInteger::from(2).power(BigInteger::from(-1)),
+            BigInteger::from_literal(&"1.5".parse().unwrap(), &()),
+            BigInteger::from_literal(&"125e-2".parse().unwrap(), &())
+        ].to_vec();
+
+        let expected_result: [Result<BigInteger, ArithmeticError>; 4] = [
+            Err(ArithmeticError::DivisionByZero),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::NotRepresentable)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn big_integers_reject_results_of_too_many_digits() {
+        let big = |value: i64| BigInteger::from(value);
+
+        assert_eq!(BigInteger::from_literal(&"1e10000".parse().unwrap(), &()), Err(ArithmeticError::Overflow));
+        assert_eq!(BigInteger::from_literal(&"1e999999999".parse().unwrap(), &()), Err(ArithmeticError::Overflow));
+        assert_eq!(big(10).power(big(10_000)), Err(ArithmeticError::Overflow));
+        assert_eq!(big(2).power(big(99_999_999_999)), Err(ArithmeticError::Overflow));
+        assert_eq!(big(1).shift_left(big(99_999_999_999)), Err(ArithmeticError::Overflow));
+        assert_eq!(big(0).shift_left(big(99_999_999_999)), Ok(big(0)));
+        assert_eq!(big(5).shift_right(big(99_999_999_999)), Ok(big(0)));
+        assert_eq!(big(-5).shift_right(big(99_999_999_999)), Ok(big(-1)));
+        assert_eq!(big(3_249).factorial(), Err(ArithmeticError::Overflow));
+        assert_eq!(big(100_000_000).factorial(), Err(ArithmeticError::Overflow));
+    }
+
+    #[test]
+    fn big_integers_are_ordered() {
+        let mut result: Vec<BigInteger> = [10, -3, 0, -20, 7]
+            .iter()
+            .map(|&value| BigInteger::from(value))
+            .collect();
+        result.sort();
+
+        let expected_result: Vec<BigInteger> = [-20, -3, 0, 7, 10]
+            .iter()
+            .map(|&value| BigInteger::from(value))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn big_integer_square_roots_are_exact() {
+        let square: BigInteger = BigInteger::from(10).pow(40);
+
+        assert_eq!(square.integer_sqrt(), Some(BigInteger::from(10).pow(20)));
+        assert_eq!((&square + &BigInteger::from(-1)).integer_sqrt(), Some(&BigInteger::from(10).pow(20) - &BigInteger::from(1)));
+        assert_eq!(BigInteger::from(-4).integer_sqrt(), None);
+        assert_eq!(Number::sqrt(square), Ok(BigInteger::from(10).pow(20)));
+        assert_eq!(Number::sqrt(BigInteger::from(2)), Err(ArithmeticError::NotRepresentable));
+        assert_eq!(BigInteger::from(-3).absolute_value(), Ok(BigInteger::from(3)));
+        assert_eq!(BigInteger::from(-3).maximum(BigInteger::from(2)), Ok(BigInteger::from(2)));
+    }
+
+    #[test]
+    fn big_integers_support_integer_division_remainder_and_factorial() {
+        let big = |value: i64| BigInteger::from(value);
+
+        assert_eq!(big(-7).integer_divide(big(2)), Ok(big(-4)));
+        assert_eq!(big(7).remainder(big(-2)), Ok(big(-1)));
+        assert_eq!(big(-6).remainder(big(3)), Ok(big(0)));
+        assert_eq!(big(1).remainder(big(0)), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(big(25).factorial().map(|value| value.to_string()), Ok(String::from("15511210043330985984000000")));
+        assert_eq!(big(-1).factorial(), Err(ArithmeticError::OutOfDomain));
+    }
+
+    #[test]
+    fn big_integers_support_bitwise_operators_on_twos_complement() {
+        let big = |value: i64| BigInteger::from(value);
+
+        assert_eq!(big(12).bit_and(big(10)), Ok(big(8)));
+        assert_eq!(big(-12).bit_or(big(10)), Ok(big(-2)));
+        assert_eq!(big(-6).bit_xor(big(-3)), Ok(big(7)));
+        assert_eq!(big(-1).bit_and(big(-256)), Ok(big(-256)));
+        assert_eq!(big(0).bit_not(), Ok(big(-1)));
+        assert_eq!(big(1).shift_left(big(100)).map(|value| value.to_string()), Ok(String::from("1267650600228229401496703205376")));
+        assert_eq!(big(-5).shift_right(big(1)), Ok(big(-3)));
+        assert_eq!(big(1).shift_left(big(-1)), Err(ArithmeticError::OutOfDomain));
+    }
+}
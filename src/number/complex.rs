@@ -0,0 +1,326 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Error};
+
+use super::{ArithmeticError, Number};
+use super::tolerance::Tolerance;
+use tokenizer::token::literal::Literal;
+
+// Complex number with double precision parts. Unlike the real floats,
+// dividing by zero is an error since there is no single complex infinity to
+// return.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex {
+    re: f64,
+    im: f64
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    pub fn re(&self) -> f64 {
+        self.re
+    }
+
+    pub fn im(&self) -> f64 {
+        self.im
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.re == 0.0 && self.im == 0.0
+    }
+
+    pub fn modulus(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn argument(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    fn product(&self, other: &Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re
+        )
+    }
+
+    // Exponentiation by squaring keeps results such as `(1+2i)^2` exact.
+    fn integer_power(&self, exponent: i64) -> Result<Complex, ArithmeticError> {
+        let mut result: Complex = Complex::new(1.0, 0.0);
+        let mut base: Complex = *self;
+        let mut remaining: u64 = exponent.unsigned_abs();
+
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                result = result.product(&base);
+            }
+            base = base.product(&base);
+            remaining /= 2;
+        }
+
+        if exponent < 0 {
+            Complex::new(1.0, 0.0).divide(result)
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Complex {
+        Complex::new(re, 0.0)
+    }
+}
+
+impl Display for Complex {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        // Adding zero turns a negative zero into a positive one.
+        let sign: char = if self.im < 0.0 { '-' } else { '+' };
+
+        write!(f, "{}{}{}i", self.re + 0.0, sign, self.im.abs())
+    }
+}
+
+impl Number for Complex {
+    type Settings = ();
+
+    fn from_literal(literal: &Literal, _settings: &()) -> Result<Complex, ArithmeticError> {
+        let value: f64 = f64::from_literal(&literal.coefficient(), &())?;
+
+        if literal.is_imaginary() {
+            Ok(Complex::new(0.0, value))
+        } else {
+            Ok(Complex::from(value))
+        }
+    }
+
+    fn plus(self, other: Complex) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(self.re + other.re, self.im + other.im))
+    }
+
+    fn minus(self, other: Complex) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(self.re - other.re, self.im - other.im))
+    }
+
+    fn times(self, other: Complex) -> Result<Complex, ArithmeticError> {
+        Ok(self.product(&other))
+    }
+
+    fn divide(self, other: Complex) -> Result<Complex, ArithmeticError> {
+        if other.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let denominator: f64 = other.re * other.re + other.im * other.im;
+
+        Ok(Complex::new(
+            (self.re * other.re + self.im * other.im) / denominator,
+            (self.im * other.re - self.re * other.im) / denominator
+        ))
+    }
+
+    // Principal value `exp(other * ln(self))`, or repeated multiplication for
+    // integer exponents.
+    fn power(self, other: Complex) -> Result<Complex, ArithmeticError> {
+        if other.im == 0.0 && other.re.fract() == 0.0 && other.re.abs() <= i32::MAX as f64 {
+            return self.integer_power(other.re as i64);
+        }
+        if self.is_zero() {
+            return if other.re > 0.0 { Ok(self) } else { Err(ArithmeticError::DivisionByZero) };
+        }
+
+        other.product(&self.ln()?).exp()
+    }
+
+    // Subtracting from zero rather than flipping signs, so that `-1` does not
+    // end up with a negative zero imaginary part, below the branch cut.
+    fn negate(self) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(0.0 - self.re, 0.0 - self.im))
+    }
+
+    // Only real numbers are ordered.
+    fn compare(&self, other: &Complex) -> Result<Ordering, ArithmeticError> {
+        if self.im != 0.0 || other.im != 0.0 {
+            return Err(ArithmeticError::Incomparable);
+        }
+
+        self.re.partial_cmp(&other.re).ok_or(ArithmeticError::Incomparable)
+    }
+
+    // Measured by the modulus of the difference, so that it holds for
+    // complex values too.
+    fn approximately_equals(&self, other: &Complex, tolerance: &Tolerance, _settings: &()) -> Result<bool, ArithmeticError> {
+        let difference: Complex = Complex::new(self.re - other.re, self.im - other.im);
+
+        Ok(self == other || difference.modulus() <= tolerance.bound(self.modulus().max(other.modulus())))
+    }
+
+    // Principal value, with a non negative real part and the imaginary part
+    // of the same sign as `self`'s.
+    fn sqrt(self) -> Result<Complex, ArithmeticError> {
+        let modulus: f64 = self.modulus();
+        let im: f64 = ((modulus - self.re) / 2.0).sqrt();
+
+        Ok(Complex::new(((modulus + self.re) / 2.0).sqrt(), if self.im < 0.0 { -im } else { im }))
+    }
+
+    fn sin(self) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh()))
+    }
+
+    fn cos(self) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(self.re.cos() * self.im.cosh(), -self.re.sin() * self.im.sinh()))
+    }
+
+    fn tan(self) -> Result<Complex, ArithmeticError> {
+        self.sin()?.divide(self.cos()?)
+    }
+
+    // Principal value, with the argument in (-pi, pi].
+    fn ln(self) -> Result<Complex, ArithmeticError> {
+        if self.is_zero() { return Err(ArithmeticError::NotRepresentable); }
+
+        Ok(Complex::new(self.modulus().ln(), self.argument()))
+    }
+
+    fn log10(self) -> Result<Complex, ArithmeticError> {
+        self.ln()?.divide(Complex::from(std::f64::consts::LN_10))
+    }
+
+    fn exp(self) -> Result<Complex, ArithmeticError> {
+        let modulus: f64 = self.re.exp();
+
+        Ok(Complex::new(modulus * self.im.cos(), modulus * self.im.sin()))
+    }
+
+    fn absolute_value(self) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::from(self.modulus()))
+    }
+
+    // Rounding applies to both parts.
+    fn floor(self) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(self.re.floor(), self.im.floor()))
+    }
+
+    fn ceil(self) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(self.re.ceil(), self.im.ceil()))
+    }
+
+    fn round(self) -> Result<Complex, ArithmeticError> {
+        Ok(Complex::new(self.re.round(), self.im.round()))
+    }
+
+    // Complex numbers have no order to round the quotient down along.
+    fn integer_divide(self, _other: Complex) -> Result<Complex, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn remainder(self, _other: Complex) -> Result<Complex, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn factorial(self) -> Result<Complex, ArithmeticError> {
+        if self.im != 0.0 { return Err(ArithmeticError::OutOfDomain); }
+
+        self.re.factorial().map(Complex::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complex_numbers_can_be_instanciated_from_literals() {
+        let result: Vec<Complex> = ["1.5", "2i", "i", "0x1Fi"]
+            .iter()
+            .map(|string| Complex::from_literal(&string.parse().unwrap(), &()).unwrap())
+            .collect();
+
+        let expected_result: Vec<Complex> = [(1.5, 0.0), (0.0, 2.0), (0.0, 1.0), (0.0, 31.0)]
+            .iter()
+            .map(|&(re, im)| Complex::new(re, im))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn complex_numbers_support_every_operator() {
+        let a: Complex = Complex::new(1.0, 2.0);
+        let b: Complex = Complex::new(3.0, -1.0);
+
+        assert_eq!(a.plus(b), Ok(Complex::new(4.0, 1.0)));
+        assert_eq!(a.minus(b), Ok(Complex::new(-2.0, 3.0)));
+        assert_eq!(a.times(b), Ok(Complex::new(5.0, 5.0)));
+        assert_eq!(Complex::new(5.0, 5.0).divide(b), Ok(a));
+        assert_eq!(a.power(Complex::from(2.0)), Ok(Complex::new(-3.0, 4.0)));
+        assert_eq!(a.power(Complex::from(-1.0)), Ok(Complex::new(0.2, -0.4)));
+        assert_eq!(a.negate(), Ok(Complex::new(-1.0, -2.0)));
+        assert_eq!(a.divide(Complex::from(0.0)), Err(ArithmeticError::DivisionByZero));
+    }
+
+    #[test]
+    fn complex_power_takes_the_principal_value() {
+        let result: Complex = Complex::from(1.0).negate().unwrap().power(Complex::from(0.5)).unwrap();
+
+        assert!(result.re().abs() < 1e-15);
+        assert!((result.im() - 1.0).abs() < 1e-15);
+
+        let euler: Complex = Complex::from(std::f64::consts::E)
+            .power(Complex::new(0.0, std::f64::consts::PI))
+            .unwrap();
+
+        assert!((euler.re() + 1.0).abs() < 1e-15);
+        assert!(euler.im().abs() < 1e-15);
+    }
+
+    #[test]
+    fn complex_numbers_support_elementary_functions() {
+        assert_eq!(Complex::from(-1.0).sqrt(), Ok(Complex::new(0.0, 1.0)));
+        assert_eq!(Complex::new(-3.0, -4.0).sqrt(), Ok(Complex::new(1.0, -2.0)));
+        assert_eq!(Complex::new(3.0, 4.0).absolute_value(), Ok(Complex::from(5.0)));
+        assert_eq!(Complex::new(1.5, -1.5).floor(), Ok(Complex::new(1.0, -2.0)));
+        assert_eq!(Complex::from(0.0).ln(), Err(ArithmeticError::NotRepresentable));
+        assert_eq!(Complex::from(1.0).minimum(Complex::from(2.0)), Err(ArithmeticError::NotRepresentable));
+        assert_eq!(Complex::from(4.0).factorial(), Ok(Complex::from(24.0)));
+        assert_eq!(Complex::new(4.0, 1.0).factorial(), Err(ArithmeticError::OutOfDomain));
+        assert_eq!(Complex::from(7.0).remainder(Complex::from(2.0)), Err(ArithmeticError::NotRepresentable));
+
+        let result: Complex = Complex::new(0.0, std::f64::consts::PI).exp().unwrap();
+        assert!((result.re() + 1.0).abs() < 1e-15 && result.im().abs() < 1e-15);
+
+        let result: Complex = Complex::new(1.0, 2.0).sin().unwrap();
+        assert!((result.re() - 3.165778513216168).abs() < 1e-14 && (result.im() - 1.9596010414216063).abs() < 1e-14);
+    }
+
+    #[test]
+    fn complex_numbers_are_displayed_in_cartesian_form() {
+        let result: Vec<String> = [(5.0, 5.0), (-1.0, -0.5), (3.0, 0.0), (-0.0, -0.0)]
+            .iter()
+            .map(|&(re, im)| Complex::new(re, im).to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["5+5i", "-1-0.5i", "3+0i", "0+0i"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn complex_numbers_are_close_when_their_difference_is_small() {
+        let tolerance: Tolerance = Tolerance { relative: 1e-6, absolute: 0.0 };
+        let result: Vec<Result<bool, ArithmeticError>> = [(3.0, 4.0 + 1e-6), (3.0, 4.0 + 1e-5), (3.0 + 1e-6, -4.0)]
+            .iter()
+            .map(|&(re, im)| Complex::new(3.0, 4.0).approximately_equals(&Complex::new(re, im), &tolerance, &()))
+            .collect();
+
+        let expected_result: Vec<Result<bool, ArithmeticError>> = [Ok(true), Ok(false), Ok(false)].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+}
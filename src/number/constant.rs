@@ -0,0 +1,127 @@
+use super::big_integer::BigInteger;
+use super::rational::Rational;
+use tokenizer::token::literal::Literal;
+
+// Mathematical constant every expression may refer to by name, and which
+// cannot be assigned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constant {
+    Pi, E, Tau, Phi
+}
+
+// Names and first 50 decimal places of each constant, more than any float
+// can hold.
+const CONSTANTS: [(&str, Constant, &str); 4] = [
+    ("pi", Constant::Pi, "3.14159265358979323846264338327950288419716939937510"),
+    ("e", Constant::E, "2.71828182845904523536028747135266249775724709369995"),
+    ("tau", Constant::Tau, "6.28318530717958647692528676655900576839433879875021"),
+    ("phi", Constant::Phi, "1.61803398874989484820458683436563811772030917980576")
+];
+
+fn quotient(numerator: &BigInteger, denominator: &BigInteger) -> BigInteger {
+    numerator.div_rem(denominator).unwrap().0
+}
+
+// `unit * arctan(1 / x)`, from its Taylor series.
+fn arctan_inverse(x: i64, unit: &BigInteger) -> BigInteger {
+    let square: BigInteger = BigInteger::from(x * x);
+    let mut term: BigInteger = quotient(unit, &BigInteger::from(x));
+    let mut sum: BigInteger = term.clone();
+    let mut k: i64 = 1;
+
+    while !term.is_zero() {
+        term = quotient(&term, &square);
+        let step: BigInteger = quotient(&term, &BigInteger::from(2 * k + 1));
+        sum = if k % 2 == 1 { &sum - &step } else { &sum + &step };
+        k += 1;
+    }
+
+    sum
+}
+
+impl Constant {
+    pub fn from_name(name: &str) -> Option<Constant> {
+        CONSTANTS.iter().find(|&&(constant, _, _)| constant == name).map(|&(_, constant, _)| constant)
+    }
+
+    pub fn name(&self) -> &'static str {
+        CONSTANTS.iter().find(|&&(_, constant, _)| constant == *self).map(|&(name, _, _)| name).unwrap()
+    }
+
+    // Literal rounding to the constant for any float type.
+    pub fn literal(&self) -> Literal {
+        let digits: &str = CONSTANTS.iter().find(|&&(_, constant, _)| constant == *self).unwrap().2;
+
+        digits.parse().unwrap()
+    }
+
+    // Value of the constant truncated to `places` decimal places, wrong by
+    // at most a few units in the last place.
+    pub fn approximation(&self, places: u32) -> Rational {
+        let unit: BigInteger = BigInteger::from(10).pow(places as u64);
+        let pi = || &(&arctan_inverse(5, &unit) * &BigInteger::from(16)) - &(&arctan_inverse(239, &unit) * &BigInteger::from(4));
+
+        let value: BigInteger = match *self {
+            Constant::Pi => pi(),
+            Constant::Tau => &pi() * &BigInteger::from(2),
+            Constant::E => {
+                let mut term: BigInteger = unit.clone();
+                let mut sum: BigInteger = unit.clone();
+                let mut k: i64 = 1;
+
+                while !term.is_zero() {
+                    term = quotient(&term, &BigInteger::from(k));
+                    sum = &sum + &term;
+                    k += 1;
+                }
+
+                sum
+            },
+            Constant::Phi => {
+                let root: BigInteger = (&(&unit * &unit) * &BigInteger::from(5)).integer_sqrt().unwrap();
+
+                quotient(&(&unit + &root), &BigInteger::from(2))
+            }
+        };
+
+        Rational::new(value, unit).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use number::Number;
+
+    #[test]
+    fn constants_are_found_by_name() {
+        let result: Vec<Option<Constant>> = ["pi", "e", "tau", "phi", "PI", "x"]
+            .iter()
+            .map(|name| Constant::from_name(name))
+            .collect();
+
+        let expected_result: Vec<Option<Constant>> = [
+            Some(Constant::Pi),
+            Some(Constant::E),
+            Some(Constant::Tau),
+            Some(Constant::Phi),
+            None,
+            None
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+        assert_eq!(Constant::Tau.name(), "tau");
+    }
+
+    #[test]
+    fn approximations_agree_with_the_tabulated_digits() {
+        let tolerance: Rational = Rational::new(BigInteger::from(1), BigInteger::from(10).pow(50)).unwrap();
+
+        for &(_, constant, _) in CONSTANTS.iter() {
+            let exact: Rational = Rational::from_literal(&constant.literal(), &()).unwrap();
+            let error: Rational = constant.approximation(60).minus(exact).and_then(Rational::absolute_value).unwrap();
+
+            assert!(error < tolerance, "{:?}", constant);
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Error};
+
+use super::{ArithmeticError, Number};
+use super::big_integer::BigInteger;
+use super::constant::Constant;
+use super::rational::Rational;
+use super::tolerance::Tolerance;
+use tokenizer::token::literal::Literal;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    // Ties go to the even neighbour, as in banking: 2.665 gives 2.66.
+    HalfEven,
+    // Ties go away from zero: 2.665 gives 2.67.
+    HalfUp,
+    // Extra digits are dropped: 2.669 gives 2.66.
+    Truncate
+}
+
+impl Rounding {
+    // `numerator / denominator` rounded to an integer, `denominator` being
+    // positive.
+    fn divide(&self, numerator: &BigInteger, denominator: &BigInteger) -> BigInteger {
+        let (quotient, remainder) = numerator.div_rem(denominator).unwrap();
+        let twice_remainder: BigInteger = &remainder.abs() * &BigInteger::from(2);
+        let away_from_zero: bool = match *self {
+            Rounding::HalfEven => twice_remainder > *denominator || (twice_remainder == *denominator && !quotient.is_even()),
+            Rounding::HalfUp => twice_remainder >= *denominator,
+            Rounding::Truncate => false
+        };
+
+        if !away_from_zero || remainder.is_zero() { return quotient; }
+
+        let step: BigInteger = BigInteger::from(if numerator.is_negative() { -1 } else { 1 });
+        &quotient + &step
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecimalSettings {
+    // Number of digits after the decimal point.
+    pub scale: u32,
+    pub rounding: Rounding
+}
+
+impl Default for DecimalSettings {
+    fn default() -> DecimalSettings {
+        DecimalSettings { scale: 2, rounding: Rounding::HalfEven }
+    }
+}
+
+// Base 10 fixed point number: `unscaled * 10^-scale`. Literals within the
+// scale are represented exactly, results of `*`, `/` and `^` are rounded
+// back to the scale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decimal {
+    unscaled: BigInteger,
+    settings: DecimalSettings
+}
+
+impl Decimal {
+    pub fn from_rational(value: &Rational, settings: &DecimalSettings) -> Decimal {
+        let scaled: BigInteger = value.numerator() * &BigInteger::from(10).pow(settings.scale as u64);
+
+        Decimal {
+            unscaled: settings.rounding.divide(&scaled, value.denominator()),
+            settings: *settings
+        }
+    }
+
+    pub fn to_rational(&self) -> Rational {
+        Rational::new(self.unscaled.clone(), BigInteger::from(10).pow(self.settings.scale as u64)).unwrap()
+    }
+
+    pub fn settings(&self) -> &DecimalSettings {
+        &self.settings
+    }
+
+    // Computes exactly on rationals and rounds the result back to the
+    // scale of `self`.
+    fn apply(
+        self,
+        other: Decimal,
+        operation: fn(Rational, Rational) -> Result<Rational, ArithmeticError>
+    ) -> Result<Decimal, ArithmeticError> {
+        let result: Rational = operation(self.to_rational(), other.to_rational())?;
+
+        Ok(Decimal::from_rational(&result, &self.settings))
+    }
+
+    fn map(self, operation: fn(Rational) -> Result<Rational, ArithmeticError>) -> Result<Decimal, ArithmeticError> {
+        Ok(Decimal::from_rational(&operation(self.to_rational())?, &self.settings))
+    }
+
+    // Transcendental functions are computed on double precision floats, so
+    // that only about 16 significant digits of their results are correct.
+    fn approximate(self, function: fn(f64) -> f64) -> Result<Decimal, ArithmeticError> {
+        let result: Rational = Rational::from_f64(function(self.to_rational().to_f64()))?;
+
+        Ok(Decimal::from_rational(&result, &self.settings))
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let scale: usize = self.settings.scale as usize;
+        let digits: String = format!("{:0>width$}", self.unscaled.abs().to_string(), width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let sign: &str = if self.unscaled.is_negative() { "-" } else { "" };
+
+        if scale == 0 { return write!(f, "{}{}", sign, integer); }
+
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl Number for Decimal {
+    type Settings = DecimalSettings;
+
+    fn from_literal(literal: &Literal, settings: &DecimalSettings) -> Result<Decimal, ArithmeticError> {
+        Rational::from_literal(literal, &()).map(|value| Decimal::from_rational(&value, settings))
+    }
+
+    // Computed to the scale, with a few more digits for the rounding.
+    fn constant(constant: Constant, settings: &DecimalSettings) -> Result<Decimal, ArithmeticError> {
+        Ok(Decimal::from_rational(&constant.approximation(settings.scale + 10), settings))
+    }
+
+    fn plus(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::plus)
+    }
+
+    fn minus(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::minus)
+    }
+
+    fn times(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::times)
+    }
+
+    fn divide(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::divide)
+    }
+
+    // Only integer exponents are supported, the power is computed exactly
+    // before being rounded.
+    fn power(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::power)
+    }
+
+    fn negate(self) -> Result<Decimal, ArithmeticError> {
+        Ok(Decimal { unscaled: -&self.unscaled, settings: self.settings })
+    }
+
+    // Values are compared whatever their scale: `1.5` equals `1.50`.
+    fn compare(&self, other: &Decimal) -> Result<Ordering, ArithmeticError> {
+        Ok(self.to_rational().cmp(&other.to_rational()))
+    }
+
+    fn equals(&self, other: &Decimal) -> Result<bool, ArithmeticError> {
+        Ok(self.to_rational() == other.to_rational())
+    }
+
+    // The tolerance is not rounded to the scale, which would often make it
+    // zero.
+    fn approximately_equals(&self, other: &Decimal, tolerance: &Tolerance, _settings: &DecimalSettings) -> Result<bool, ArithmeticError> {
+        self.to_rational().approximately_equals(&other.to_rational(), tolerance, &())
+    }
+
+    fn sqrt(self) -> Result<Decimal, ArithmeticError> {
+        self.approximate(f64::sqrt)
+    }
+
+    fn sin(self) -> Result<Decimal, ArithmeticError> {
+        self.approximate(f64::sin)
+    }
+
+    fn cos(self) -> Result<Decimal, ArithmeticError> {
+        self.approximate(f64::cos)
+    }
+
+    fn tan(self) -> Result<Decimal, ArithmeticError> {
+        self.approximate(f64::tan)
+    }
+
+    fn ln(self) -> Result<Decimal, ArithmeticError> {
+        self.approximate(f64::ln)
+    }
+
+    fn log10(self) -> Result<Decimal, ArithmeticError> {
+        self.approximate(f64::log10)
+    }
+
+    fn exp(self) -> Result<Decimal, ArithmeticError> {
+        self.approximate(f64::exp)
+    }
+
+    fn absolute_value(self) -> Result<Decimal, ArithmeticError> {
+        Ok(Decimal { unscaled: self.unscaled.abs(), settings: self.settings })
+    }
+
+    fn floor(self) -> Result<Decimal, ArithmeticError> {
+        self.map(Rational::floor)
+    }
+
+    fn ceil(self) -> Result<Decimal, ArithmeticError> {
+        self.map(Rational::ceil)
+    }
+
+    fn round(self) -> Result<Decimal, ArithmeticError> {
+        self.map(Rational::round)
+    }
+
+    fn minimum(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::minimum)
+    }
+
+    fn maximum(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::maximum)
+    }
+
+    // Computed exactly, unlike rounding down the quotient rounded to the
+    // scale.
+    fn integer_divide(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::integer_divide)
+    }
+
+    fn remainder(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::remainder)
+    }
+
+    fn factorial(self) -> Result<Decimal, ArithmeticError> {
+        self.map(Rational::factorial)
+    }
+
+    fn bit_and(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::bit_and)
+    }
+
+    fn bit_or(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::bit_or)
+    }
+
+    fn bit_xor(self, other: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(other, Rational::bit_xor)
+    }
+
+    fn bit_not(self) -> Result<Decimal, ArithmeticError> {
+        self.map(Rational::bit_not)
+    }
+
+    fn shift_left(self, amount: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(amount, Rational::shift_left)
+    }
+
+    fn shift_right(self, amount: Decimal) -> Result<Decimal, ArithmeticError> {
+        self.apply(amount, Rational::shift_right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(string: &str, settings: &DecimalSettings) -> Decimal {
+        Decimal::from_literal(&string.parse().unwrap(), settings).unwrap()
+    }
+
+    #[test]
+    fn decimals_are_displayed_with_all_digits_of_their_scale() {
+        let settings: DecimalSettings = DecimalSettings { scale: 3, rounding: Rounding::HalfEven };
+        let result: Vec<String> = ["0.1", "12", "0.0005", "1234.5678"]
+            .iter()
+            .map(|string| decimal(string, &settings).to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["0.100", "12.000", "0.000", "1234.568"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn decimal_additions_are_exact() {
+        let settings: DecimalSettings = DecimalSettings::default();
+        let result: Decimal = decimal("0.1", &settings).plus(decimal("0.2", &settings)).unwrap();
+
+        assert_eq!(result, decimal("0.3", &settings));
+    }
+
+    #[test]
+    fn decimals_are_rounded_following_the_rounding_mode() {
+        let result: Vec<String> = [Rounding::HalfEven, Rounding::HalfUp, Rounding::Truncate]
+            .iter()
+            .map(|&rounding| {
+                let settings: DecimalSettings = DecimalSettings { scale: 2, rounding };
+                [
+                    decimal("2.665", &settings),
+                    decimal("2.675", &settings),
+                    decimal("2.665", &settings).negate().unwrap(),
+                    decimal("2.669", &settings)
+                ]
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "2.66 2.68 -2.66 2.67",
+            "2.67 2.68 -2.67 2.67",
+            "2.66 2.67 -2.66 2.66"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn decimal_divisions_and_powers_are_rounded_to_the_scale() {
+        let settings: DecimalSettings = DecimalSettings { scale: 4, rounding: Rounding::HalfEven };
+        let result: Vec<String> = [
+            decimal("2", &settings).divide(decimal("3", &settings)),
+            decimal("1.05", &settings).power(decimal("10", &settings)),
+            decimal("2", &settings).power(decimal("2", &settings).negate().unwrap())
+        ]
+            .iter()
+            .map(|result| result.clone().unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["0.6667", "1.6289", "0.2500"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn decimals_reject_division_by_zero_and_fractional_powers() {
+        let settings: DecimalSettings = DecimalSettings::default();
+        let result: Vec<Result<Decimal, ArithmeticError>> = [
+            decimal("1", &settings).divide(decimal("0", &settings)),
+            decimal("2", &settings).power(decimal("0.5", &settings))
+        ].to_vec();
+
+        let expected_result: [Result<Decimal, ArithmeticError>; 2] = [
+            Err(ArithmeticError::DivisionByZero),
+            Err(ArithmeticError::NotRepresentable)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn decimals_reject_literals_of_too_many_digits() {
+        let settings: DecimalSettings = DecimalSettings::default();
+        let result: Vec<Result<Decimal, ArithmeticError>> = ["1e-999999999", "1e999999999"]
+            .iter()
+            .map(|string| Decimal::from_literal(&string.parse().unwrap(), &settings))
+            .collect();
+
+        let expected_result: Vec<Result<Decimal, ArithmeticError>> = vec![Err(ArithmeticError::Overflow); 2];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn decimal_functions_are_rounded_to_the_scale() {
+        let settings: DecimalSettings = DecimalSettings { scale: 4, rounding: Rounding::HalfEven };
+        let result: Vec<Result<String, ArithmeticError>> = [
+            decimal("2", &settings).sqrt(),
+            decimal("1", &settings).exp(),
+            decimal("2.5", &settings).negate().and_then(Decimal::round),
+            decimal("2.5", &settings).negate().and_then(Decimal::floor),
+            decimal("2.5", &settings).negate().and_then(Decimal::absolute_value),
+            decimal("1.25", &settings).minimum(decimal("1.2", &settings)),
+            decimal("1", &settings).negate().and_then(Decimal::sqrt),
+            decimal("0", &settings).ln()
+        ].iter().map(|result| result.clone().map(|value| value.to_string())).collect();
+
+        let expected_result: [Result<String, ArithmeticError>; 8] = [
+            Ok(String::from("1.4142")),
+            Ok(String::from("2.7183")),
+            Ok(String::from("-3.0000")),
+            Ok(String::from("-3.0000")),
+            Ok(String::from("2.5000")),
+            Ok(String::from("1.2000")),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::NotRepresentable)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn decimal_integer_division_is_not_affected_by_rounding() {
+        let settings: DecimalSettings = DecimalSettings { scale: 2, rounding: Rounding::HalfEven };
+        let result: Vec<Result<String, ArithmeticError>> = [
+            decimal("0.999", &DecimalSettings { scale: 3, ..settings }).integer_divide(decimal("1", &settings)),
+            decimal("7.5", &settings).remainder(decimal("2", &settings)),
+            decimal("5", &settings).factorial()
+        ].iter().map(|result| result.clone().map(|value| value.to_string())).collect();
+
+        let expected_result: [Result<String, ArithmeticError>; 3] = [
+            Ok(String::from("0.000")),
+            Ok(String::from("1.50")),
+            Ok(String::from("120.00"))
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn constants_have_every_digit_of_the_scale() {
+        let settings: DecimalSettings = DecimalSettings { scale: 60, rounding: Rounding::HalfEven };
+        let result: Result<String, ArithmeticError> = Decimal::constant(Constant::Pi, &settings).map(|value| value.to_string());
+
+        assert_eq!(result, Ok(String::from("3.141592653589793238462643383279502884197169399375105820974945")));
+    }
+
+    #[test]
+    fn approximate_equality_does_not_round_the_tolerance() {
+        let settings: DecimalSettings = DecimalSettings::default();
+        let tolerance: Tolerance = Tolerance { relative: 0.0, absolute: 0.005 };
+
+        assert_eq!(decimal("1.00", &settings).approximately_equals(&decimal("1.01", &settings), &tolerance, &settings), Ok(false));
+        assert_eq!(decimal("1.00", &settings).approximately_equals(&decimal("1.00", &settings), &tolerance, &settings), Ok(true));
+    }
+}
@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+
+use super::{ArithmeticError, Number};
+use super::tolerance::Tolerance;
+use tokenizer::token::literal::Literal;
+use tokenizer::token::literal::radix::Radix;
+
+// Integer held by a float, for the bitwise operators.
+fn to_integer(value: f64) -> Result<i64, ArithmeticError> {
+    if value.fract() != 0.0 { return Err(ArithmeticError::OutOfDomain); }
+    if value >= 2f64.powi(63) || value < -2f64.powi(63) { return Err(ArithmeticError::Overflow); }
+
+    Ok(value as i64)
+}
+
+fn integer_operation(
+    first: f64,
+    second: f64,
+    operation: fn(i64, i64) -> Result<i64, ArithmeticError>
+) -> Result<f64, ArithmeticError> {
+    operation(to_integer(first)?, to_integer(second)?).map(|result| result as f64)
+}
+
+// Floating point numbers follow IEEE 754: dividing by zero gives an infinity
+// rather than an error.
+macro_rules! impl_number_for_float {
+    ($float:ident) => {
+        impl Number for $float {
+            type Settings = ();
+
+            fn from_literal(literal: &Literal, _settings: &()) -> Result<$float, ArithmeticError> {
+                if literal.is_imaginary() {
+                    return Err(ArithmeticError::NotRepresentable);
+                }
+                match literal.radix() {
+                    Radix::Decimal => {
+                        literal.to_string().parse::<$float>().map_err(|_| ArithmeticError::NotRepresentable)
+                    },
+                    radix => {
+                        let base: $float = radix.base() as $float;
+
+                        Ok(
+                            literal.integer()
+                                .iter()
+                                .fold(0.0, |value, digit| value * base + digit.value() as $float)
+                        )
+                    }
+                }
+            }
+
+            fn plus(self, other: $float) -> Result<$float, ArithmeticError> {
+                Ok(self + other)
+            }
+
+            fn minus(self, other: $float) -> Result<$float, ArithmeticError> {
+                Ok(self - other)
+            }
+
+            fn times(self, other: $float) -> Result<$float, ArithmeticError> {
+                Ok(self * other)
+            }
+
+            fn divide(self, other: $float) -> Result<$float, ArithmeticError> {
+                Ok(self / other)
+            }
+
+            fn power(self, other: $float) -> Result<$float, ArithmeticError> {
+                Ok(self.powf(other))
+            }
+
+            fn negate(self) -> Result<$float, ArithmeticError> {
+                Ok(-self)
+            }
+
+            fn compare(&self, other: &$float) -> Result<Ordering, ArithmeticError> {
+                self.partial_cmp(other).ok_or(ArithmeticError::Incomparable)
+            }
+
+            fn approximately_equals(&self, other: &$float, tolerance: &Tolerance, _settings: &()) -> Result<bool, ArithmeticError> {
+                let magnitude: f64 = self.abs().max(other.abs()) as f64;
+
+                Ok(self == other || ((self - other).abs() as f64) <= tolerance.bound(magnitude))
+            }
+
+            // Unlike the division, taking the remainder by zero has no limit
+            // to return.
+            fn remainder(self, other: $float) -> Result<$float, ArithmeticError> {
+                if other == 0.0 { return Err(ArithmeticError::DivisionByZero); }
+
+                let remainder: $float = self % other;
+                if remainder != 0.0 && (remainder < 0.0) != (other < 0.0) {
+                    Ok(remainder + other)
+                } else {
+                    Ok(remainder)
+                }
+            }
+
+            // Derived from the exact remainder rather than by rounding down
+            // the quotient, which may already be rounded up to an integer.
+            fn integer_divide(self, other: $float) -> Result<$float, ArithmeticError> {
+                let remainder: $float = self.remainder(other)?;
+
+                Ok(((self - remainder) / other).round())
+            }
+
+            // Infinite once the product overflows.
+            fn factorial(self) -> Result<$float, ArithmeticError> {
+                if self < 0.0 || self.fract() != 0.0 { return Err(ArithmeticError::OutOfDomain); }
+
+                let mut product: $float = 1.0;
+                let mut factor: $float = 2.0;
+                while factor <= self && product.is_finite() {
+                    product *= factor;
+                    factor += 1.0;
+                }
+
+                Ok(product)
+            }
+
+            fn bit_and(self, other: $float) -> Result<$float, ArithmeticError> {
+                integer_operation(self as f64, other as f64, i64::bit_and).map(|result| result as $float)
+            }
+
+            fn bit_or(self, other: $float) -> Result<$float, ArithmeticError> {
+                integer_operation(self as f64, other as f64, i64::bit_or).map(|result| result as $float)
+            }
+
+            fn bit_xor(self, other: $float) -> Result<$float, ArithmeticError> {
+                integer_operation(self as f64, other as f64, i64::bit_xor).map(|result| result as $float)
+            }
+
+            fn bit_not(self) -> Result<$float, ArithmeticError> {
+                to_integer(self as f64).map(|value| !value as $float)
+            }
+
+            fn shift_left(self, amount: $float) -> Result<$float, ArithmeticError> {
+                integer_operation(self as f64, amount as f64, i64::shift_left).map(|result| result as $float)
+            }
+
+            fn shift_right(self, amount: $float) -> Result<$float, ArithmeticError> {
+                integer_operation(self as f64, amount as f64, i64::shift_right).map(|result| result as $float)
+            }
+
+            fn sqrt(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::sqrt(self))
+            }
+
+            fn sin(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::sin(self))
+            }
+
+            fn cos(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::cos(self))
+            }
+
+            fn tan(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::tan(self))
+            }
+
+            fn ln(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::ln(self))
+            }
+
+            fn log10(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::log10(self))
+            }
+
+            fn exp(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::exp(self))
+            }
+
+            fn absolute_value(self) -> Result<$float, ArithmeticError> {
+                Ok(self.abs())
+            }
+
+            fn floor(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::floor(self))
+            }
+
+            fn ceil(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::ceil(self))
+            }
+
+            fn round(self) -> Result<$float, ArithmeticError> {
+                Ok(<$float>::round(self))
+            }
+
+            fn minimum(self, other: $float) -> Result<$float, ArithmeticError> {
+                Ok(self.min(other))
+            }
+
+            fn maximum(self, other: $float) -> Result<$float, ArithmeticError> {
+                Ok(self.max(other))
+            }
+        }
+    }
+}
+
+impl_number_for_float!(f32);
+impl_number_for_float!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floats_can_be_instanciated_from_literals() {
+        let result: Vec<f64> = ["1.5", ".25", "6.02e23", "0x1F", "0b1010"]
+            .iter()
+            .map(|string| f64::from_literal(&string.parse().unwrap(), &()).unwrap())
+            .collect();
+
+        let expected_result: [f64; 5] = [1.5, 0.25, 6.02e23, 31.0, 10.0];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn floats_keep_more_digits_in_double_precision() {
+        let literal: Literal = "16777217".parse().unwrap();
+
+        assert_eq!(f32::from_literal(&literal, &()), Ok(16777216.0));
+        assert_eq!(f64::from_literal(&literal, &()), Ok(16777217.0));
+    }
+
+    #[test]
+    fn floats_divided_by_zero_are_infinite() {
+        assert_eq!(1.0f64.divide(0.0), Ok(f64::INFINITY));
+    }
+
+    #[test]
+    fn floats_support_integer_division_remainder_and_factorial() {
+        assert_eq!(1f64.integer_divide(0.1), Ok(9.0));
+        assert_eq!((-7f64).remainder(2.0), Ok(1.0));
+        assert_eq!(7.5f64.remainder(-2.0), Ok(-0.5));
+        assert_eq!(1f64.remainder(0.0), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(20f64.factorial(), Ok(2432902008176640000.0));
+        assert_eq!(171f64.factorial(), Ok(f64::INFINITY));
+        assert_eq!(f64::NAN.factorial(), Err(ArithmeticError::OutOfDomain));
+    }
+
+    #[test]
+    fn floats_support_bitwise_operators_on_integers_only() {
+        assert_eq!(255f64.bit_and(15.0), Ok(15.0));
+        assert_eq!(12f64.bit_xor(10.0), Ok(6.0));
+        assert_eq!((-1f64).shift_left(4.0), Ok(-16.0));
+        assert_eq!((-1f64).shift_left(63.0), Ok(-2f64.powi(63)));
+        assert_eq!(1f64.shift_left(63.0), Err(ArithmeticError::Overflow));
+        assert_eq!(0f64.bit_not(), Ok(-1.0));
+        assert_eq!(1.5f64.bit_or(1.0), Err(ArithmeticError::OutOfDomain));
+        assert_eq!(1f64.shift_right(0.5), Err(ArithmeticError::OutOfDomain));
+        assert_eq!(1e19f64.bit_and(1.0), Err(ArithmeticError::Overflow));
+    }
+
+    #[test]
+    fn floats_support_elementary_functions() {
+        assert_eq!(Number::sqrt(4f64), Ok(2.0));
+        assert_eq!(Number::round(-2.5f64), Ok(-3.0));
+        assert_eq!(Number::floor(-2.5f64), Ok(-3.0));
+        assert_eq!(Number::ceil(-2.5f64), Ok(-2.0));
+        assert_eq!(Number::absolute_value(-2.5f64), Ok(2.5));
+        assert_eq!(Number::log10(1000f64), Ok(3.0));
+        assert_eq!(Number::minimum(2f64, 3.0), Ok(2.0));
+        assert_eq!(Number::maximum(2f64, 3.0), Ok(3.0));
+        assert!(Number::sqrt(-1f64).unwrap().is_nan());
+    }
+}
@@ -0,0 +1,339 @@
+use std::cmp::Ordering;
+use std::convert::TryInto;
+
+use super::{integer_digits, ArithmeticError, Number};
+use super::tolerance::Tolerance;
+use tokenizer::token::literal::Literal;
+use tokenizer::token::literal::digit::Digit;
+use tokenizer::token::literal::radix::Radix;
+
+fn digits_value(digits: &[Digit], base: u32) -> Result<i64, ArithmeticError> {
+    digits.iter().try_fold(0i64, |value, digit| {
+        value
+            .checked_mul(base as i64)
+            .and_then(|value| value.checked_add(digit.value() as i64))
+            .ok_or(ArithmeticError::Overflow)
+    })
+}
+
+// Integers are checked: overflows are errors and the division truncates
+// toward zero, like Rust's own `/`.
+impl Number for i64 {
+    type Settings = ();
+
+    fn from_literal(literal: &Literal, _settings: &()) -> Result<i64, ArithmeticError> {
+        if literal.is_imaginary() {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+        if literal.radix() != Radix::Decimal {
+            return digits_value(literal.integer(), literal.radix().base());
+        }
+
+        let (digits, power): (Vec<Digit>, u64) = integer_digits(literal)?;
+        let power: u32 = power.try_into().map_err(|_| ArithmeticError::Overflow)?;
+        let value: i64 = digits_value(&digits, 10)?;
+
+        10i64.checked_pow(power)
+            .and_then(|power| value.checked_mul(power))
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    fn plus(self, other: i64) -> Result<i64, ArithmeticError> {
+        self.checked_add(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    fn minus(self, other: i64) -> Result<i64, ArithmeticError> {
+        self.checked_sub(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    fn times(self, other: i64) -> Result<i64, ArithmeticError> {
+        self.checked_mul(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    fn divide(self, other: i64) -> Result<i64, ArithmeticError> {
+        if other == 0 { return Err(ArithmeticError::DivisionByZero); }
+
+        self.checked_div(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    fn power(self, other: i64) -> Result<i64, ArithmeticError> {
+        if other < 0 {
+            return match self {
+                0 => Err(ArithmeticError::DivisionByZero),
+                1 => Ok(1),
+                -1 => Ok(if other % 2 == 0 { 1 } else { -1 }),
+                _ => Err(ArithmeticError::NotRepresentable)
+            };
+        }
+
+        let exponent: u32 = other.try_into().map_err(|_| ArithmeticError::Overflow)?;
+        self.checked_pow(exponent).ok_or(ArithmeticError::Overflow)
+    }
+
+    fn negate(self) -> Result<i64, ArithmeticError> {
+        self.checked_neg().ok_or(ArithmeticError::Overflow)
+    }
+
+    fn compare(&self, other: &i64) -> Result<Ordering, ArithmeticError> {
+        Ok(self.cmp(other))
+    }
+
+    // Integers are exact, so that only equal ones are close enough.
+    fn approximately_equals(&self, other: &i64, _tolerance: &Tolerance, _settings: &()) -> Result<bool, ArithmeticError> {
+        self.equals(other)
+    }
+
+    fn integer_divide(self, other: i64) -> Result<i64, ArithmeticError> {
+        let quotient: i64 = self.divide(other)?;
+        let remainder: i64 = self % other;
+
+        Ok(if remainder != 0 && (remainder < 0) != (other < 0) { quotient - 1 } else { quotient })
+    }
+
+    fn remainder(self, other: i64) -> Result<i64, ArithmeticError> {
+        if other == 0 { return Err(ArithmeticError::DivisionByZero); }
+
+        // Only `i64::MIN % -1` wraps, to the right result of zero.
+        let remainder: i64 = self.wrapping_rem(other);
+        Ok(if remainder != 0 && (remainder < 0) != (other < 0) { remainder + other } else { remainder })
+    }
+
+    fn factorial(self) -> Result<i64, ArithmeticError> {
+        if self < 0 { return Err(ArithmeticError::OutOfDomain); }
+
+        (2..=self).try_fold(1i64, |product, factor| product.checked_mul(factor).ok_or(ArithmeticError::Overflow))
+    }
+
+    fn bit_and(self, other: i64) -> Result<i64, ArithmeticError> {
+        Ok(self & other)
+    }
+
+    fn bit_or(self, other: i64) -> Result<i64, ArithmeticError> {
+        Ok(self | other)
+    }
+
+    fn bit_xor(self, other: i64) -> Result<i64, ArithmeticError> {
+        Ok(self ^ other)
+    }
+
+    fn bit_not(self) -> Result<i64, ArithmeticError> {
+        Ok(!self)
+    }
+
+    fn shift_left(self, amount: i64) -> Result<i64, ArithmeticError> {
+        if amount < 0 { return Err(ArithmeticError::OutOfDomain); }
+        if self == 0 { return Ok(0); }
+        if amount >= 64 { return Err(ArithmeticError::Overflow); }
+
+        // The shift overflows when shifting back does not give `self` again.
+        let shifted: i64 = self << amount;
+        if shifted >> amount != self { return Err(ArithmeticError::Overflow); }
+
+        Ok(shifted)
+    }
+
+    // Past 63 bits, only the sign is left.
+    fn shift_right(self, amount: i64) -> Result<i64, ArithmeticError> {
+        if amount < 0 { return Err(ArithmeticError::OutOfDomain); }
+
+        Ok(self >> amount.min(63))
+    }
+
+    // Only perfect squares have an integer root.
+    fn sqrt(self) -> Result<i64, ArithmeticError> {
+        match self.checked_isqrt() {
+            Some(root) if root * root == self => Ok(root),
+            _ => Err(ArithmeticError::NotRepresentable)
+        }
+    }
+
+    fn absolute_value(self) -> Result<i64, ArithmeticError> {
+        self.checked_abs().ok_or(ArithmeticError::Overflow)
+    }
+
+    fn floor(self) -> Result<i64, ArithmeticError> {
+        Ok(self)
+    }
+
+    fn ceil(self) -> Result<i64, ArithmeticError> {
+        Ok(self)
+    }
+
+    fn round(self) -> Result<i64, ArithmeticError> {
+        Ok(self)
+    }
+
+    fn minimum(self, other: i64) -> Result<i64, ArithmeticError> {
+        Ok(self.min(other))
+    }
+
+    fn maximum(self, other: i64) -> Result<i64, ArithmeticError> {
+        Ok(self.max(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_can_be_instanciated_from_literals() {
+        let result: Vec<Result<i64, ArithmeticError>> = [
+            "42", "0xFF", "2e3", "3.00", "1.5e1", "100e-2", "0e-99999999999999999999",
+            "1.5", "1e-2", "1.50e-1", "99999999999999999999", "1e19"
+        ]
+            .iter()
+            .map(|string| i64::from_literal(&string.parse().unwrap(), &()))
+            .collect();
+
+        let expected_result: [Result<i64, ArithmeticError>; 12] = [
+            Ok(42),
+            Ok(255),
+            Ok(2000),
+            Ok(3),
+            Ok(15),
+            Ok(1),
+            Ok(0),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::Overflow),
+            Err(ArithmeticError::Overflow)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn integer_division_truncates_and_rejects_zero() {
+        let result: Vec<Result<i64, ArithmeticError>> = [(7, 2), (-7, 2), (1, 0)]
+            .iter()
+            .map(|&(first, second): &(i64, i64)| first.divide(second))
+            .collect();
+
+        let expected_result: [Result<i64, ArithmeticError>; 3] = [
+            Ok(3),
+            Ok(-3),
+            Err(ArithmeticError::DivisionByZero)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn integer_operations_report_overflows() {
+        let result: Vec<Result<i64, ArithmeticError>> = [
+            i64::MAX.plus(1),
+            i64::MIN.minus(1),
+            i64::MAX.times(2),
+            2i64.power(64),
+            i64::MIN.negate()
+        ].to_vec();
+
+        assert!(result.iter().all(|value| *value == Err(ArithmeticError::Overflow)));
+    }
+
+    #[test]
+    fn integer_exponentiation_stays_integral() {
+        let result: Vec<Result<i64, ArithmeticError>> = [(2, 10), (1, -3), (-1, -3), (2, -1)]
+            .iter()
+            .map(|&(first, second): &(i64, i64)| first.power(second))
+            .collect();
+
+        let expected_result: [Result<i64, ArithmeticError>; 4] = [
+            Ok(1024),
+            Ok(1),
+            Ok(-1),
+            Err(ArithmeticError::NotRepresentable)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn integer_functions_stay_integral() {
+        let result: Vec<Result<i64, ArithmeticError>> = [
+            Number::sqrt(49i64),
+            Number::sqrt(50i64),
+            Number::sqrt(-4i64),
+            i64::MIN.absolute_value(),
+            Number::round(7i64),
+            3i64.minimum(-2),
+            Number::sin(0i64)
+        ].to_vec();
+
+        let expected_result: [Result<i64, ArithmeticError>; 7] = [
+            Ok(7),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::NotRepresentable),
+            Err(ArithmeticError::Overflow),
+            Ok(7),
+            Ok(-2),
+            Err(ArithmeticError::NotRepresentable)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn integers_support_integer_division_remainder_and_factorial() {
+        let result: Vec<Result<i64, ArithmeticError>> = [
+            (-7i64).integer_divide(2),
+            (-7i64).remainder(2),
+            i64::MIN.remainder(-1),
+            i64::MIN.integer_divide(-1),
+            1i64.remainder(0),
+            20i64.factorial(),
+            21i64.factorial(),
+            (-1i64).factorial()
+        ].to_vec();
+
+        let expected_result: [Result<i64, ArithmeticError>; 8] = [
+            Ok(-4),
+            Ok(1),
+            Ok(0),
+            Err(ArithmeticError::Overflow),
+            Err(ArithmeticError::DivisionByZero),
+            Ok(2432902008176640000),
+            Err(ArithmeticError::Overflow),
+            Err(ArithmeticError::OutOfDomain)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn integers_support_bitwise_operators_and_shifts() {
+        let result: Vec<Result<i64, ArithmeticError>> = [
+            12i64.bit_and(10),
+            12i64.bit_or(10),
+            12i64.bit_xor(10),
+            5i64.bit_not(),
+            1i64.shift_left(62),
+            1i64.shift_left(63),
+            (-1i64).shift_left(63),
+            (-3i64).shift_left(62),
+            0i64.shift_left(100),
+            (-5i64).shift_right(1),
+            (-5i64).shift_right(100),
+            1i64.shift_right(-1)
+        ].to_vec();
+
+        let expected_result: [Result<i64, ArithmeticError>; 12] = [
+            Ok(8),
+            Ok(14),
+            Ok(6),
+            Ok(-6),
+            Ok(4611686018427387904),
+            Err(ArithmeticError::Overflow),
+            Ok(i64::MIN),
+            Err(ArithmeticError::Overflow),
+            Ok(0),
+            Ok(-3),
+            Ok(-1),
+            Err(ArithmeticError::OutOfDomain)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+}
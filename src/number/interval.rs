@@ -0,0 +1,519 @@
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter, Error};
+
+use super::{ArithmeticError, Number};
+use super::rational::Rational;
+use tokenizer::token::literal::Literal;
+
+// Closed range of double precision floats guaranteed to contain the exact
+// result. Every bound is rounded outward whenever the float operation giving
+// it is not exact, and may be infinite after a division by an interval
+// containing zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+    lower: f64,
+    upper: f64
+}
+
+// Bounds around `value`, the rounded result of a float operation, given the
+// `error` left by the rounding (exact result minus `value`). An unknown (NaN)
+// error widens both bounds.
+fn bounds(value: f64, error: f64) -> (f64, f64) {
+    let lower: f64 = if error >= 0.0 { value } else { value.next_down() };
+    let upper: f64 = if error <= 0.0 { value } else { value.next_up() };
+
+    (lower, upper)
+}
+
+// Error-free transformations giving the rounding error of each operation.
+fn sum(a: f64, b: f64) -> (f64, f64) {
+    let value: f64 = a + b;
+    let b_part: f64 = value - a;
+
+    bounds(value, (a - (value - b_part)) + (b - b_part))
+}
+
+fn product(a: f64, b: f64) -> (f64, f64) {
+    // Zero times an infinite bound is zero: that bound is never reached.
+    if a == 0.0 || b == 0.0 { return (0.0, 0.0); }
+
+    let value: f64 = a * b;
+
+    bounds(value, a.mul_add(b, -value))
+}
+
+fn quotient(a: f64, b: f64) -> (f64, f64) {
+    if a == 0.0 || (a.is_finite() && b.is_infinite()) { return (0.0, 0.0); }
+
+    let value: f64 = a / b;
+    let remainder: f64 = (-value).mul_add(b, a);
+
+    bounds(value, if remainder == 0.0 { 0.0 } else { remainder * b.signum() })
+}
+
+fn root(a: f64) -> (f64, f64) {
+    let value: f64 = a.sqrt();
+
+    bounds(value, (-value).mul_add(value, a))
+}
+
+// Bounds around the results of library functions, which are not correctly
+// rounded but stay within a float of the exact value.
+fn widened(lower: f64, upper: f64) -> Interval {
+    Interval { lower: lower.next_down().next_down(), upper: upper.next_up().next_up() }
+}
+
+// Extrema of `sin` and `cos` closer than this to a bound, in periods of pi,
+// are assumed to be reached to cater for the rounding of pi.
+const SLACK: f64 = 1e-9;
+
+impl Interval {
+    pub fn new(lower: f64, upper: f64) -> Result<Interval, ArithmeticError> {
+        if lower > upper || lower.is_nan() || upper.is_nan() {
+            return Err(ArithmeticError::EmptyInterval);
+        }
+
+        Ok(Interval { lower, upper })
+    }
+
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    fn entire() -> Interval {
+        Interval { lower: f64::NEG_INFINITY, upper: f64::INFINITY }
+    }
+
+    // Smallest interval holding all of the bounds.
+    fn hull(bounds: &[(f64, f64)]) -> Interval {
+        Interval {
+            lower: bounds.iter().map(|&(lower, _)| lower).fold(f64::INFINITY, f64::min),
+            upper: bounds.iter().map(|&(_, upper)| upper).fold(f64::NEG_INFINITY, f64::max)
+        }
+    }
+
+    fn product(&self, other: &Interval) -> Interval {
+        Interval::hull(&[
+            product(self.lower, other.lower),
+            product(self.lower, other.upper),
+            product(self.upper, other.lower),
+            product(self.upper, other.upper)
+        ])
+    }
+
+    // Exponentiation by squaring of a single value, each product enclosing
+    // the exact one.
+    fn value_power(value: f64, mut exponent: u64) -> Interval {
+        let mut result: Interval = Interval::from(1.0);
+        let mut base: Interval = Interval::from(value);
+
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = result.product(&base);
+            }
+            base = base.product(&base);
+            exponent /= 2;
+        }
+
+        result
+    }
+
+    // Whether the interval holds `phase + k pi` for some integer `k`, even
+    // when `even` is set.
+    fn reaches(&self, phase: f64, even: bool) -> bool {
+        let first: f64 = ((self.lower - phase) / PI - SLACK).ceil();
+        let last: f64 = ((self.upper - phase) / PI + SLACK).floor();
+
+        (first as i64..=last as i64).any(|k| !even || k.rem_euclid(2) == 0)
+    }
+
+    // Range of `sin` or `cos`, which are extremal at `phase + k pi` with a
+    // maximum for even values of `k`.
+    fn periodic(&self, function: fn(f64) -> f64, phase: f64) -> Interval {
+        if (self.upper - self.lower).partial_cmp(&(2.0 * PI)) != Some(Ordering::Less) {
+            return Interval { lower: -1.0, upper: 1.0 };
+        }
+
+        let (lower, upper): (f64, f64) = (function(self.lower), function(self.upper));
+        let result: Interval = widened(lower.min(upper), lower.max(upper));
+        let maximum: bool = self.reaches(phase, true);
+        let minimum: bool = self.reaches(phase + PI, true);
+
+        Interval {
+            lower: if minimum { -1.0 } else { result.lower.max(-1.0) },
+            upper: if maximum { 1.0 } else { result.upper.min(1.0) }
+        }
+    }
+
+    fn integer_power(&self, exponent: i64) -> Result<Interval, ArithmeticError> {
+        let lower: Interval = Interval::value_power(self.lower, exponent.unsigned_abs());
+        let upper: Interval = Interval::value_power(self.upper, exponent.unsigned_abs());
+        let mut result: Interval = Interval::hull(&[(lower.lower, lower.upper), (upper.lower, upper.upper)]);
+
+        // Even powers reach their minimum at zero.
+        if exponent % 2 == 0 && self.contains(0.0) && exponent != 0 {
+            result.lower = 0.0;
+        }
+
+        if exponent < 0 {
+            Interval::from(1.0).divide(result)
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(value: f64) -> Interval {
+        Interval { lower: value, upper: value }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "[{}, {}]", self.lower, self.upper)
+    }
+}
+
+impl Number for Interval {
+    type Settings = ();
+
+    // The nearest float to the literal, widened to the next float on the
+    // side of the exact value when they differ.
+    fn from_literal(literal: &Literal, _settings: &()) -> Result<Interval, ArithmeticError> {
+        let value: f64 = f64::from_literal(literal, &())?;
+        if value.is_infinite() {
+            return Ok(Interval { lower: f64::MAX, upper: value });
+        }
+
+        match Rational::from_f64(value)?.cmp(&Rational::from_literal(literal, &())?) {
+            Ordering::Less => Ok(Interval { lower: value, upper: value.next_up() }),
+            Ordering::Equal => Ok(Interval::from(value)),
+            Ordering::Greater => Ok(Interval { lower: value.next_down(), upper: value })
+        }
+    }
+
+    fn plus(self, other: Interval) -> Result<Interval, ArithmeticError> {
+        Ok(Interval {
+            lower: sum(self.lower, other.lower).0,
+            upper: sum(self.upper, other.upper).1
+        })
+    }
+
+    fn minus(self, other: Interval) -> Result<Interval, ArithmeticError> {
+        self.plus(other.negate()?)
+    }
+
+    fn times(self, other: Interval) -> Result<Interval, ArithmeticError> {
+        Ok(self.product(&other))
+    }
+
+    // Dividing by an interval containing zero gives every value reachable
+    // from both sides of zero, hence one or two infinite bounds. Only
+    // dividing by exactly zero is an error.
+    fn divide(self, other: Interval) -> Result<Interval, ArithmeticError> {
+        if other.lower == 0.0 && other.upper == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        if !other.contains(0.0) {
+            return Ok(Interval::hull(&[
+                quotient(self.lower, other.lower),
+                quotient(self.lower, other.upper),
+                quotient(self.upper, other.lower),
+                quotient(self.upper, other.upper)
+            ]));
+        }
+        if self.contains(0.0) || (other.lower < 0.0 && other.upper > 0.0) {
+            return Ok(Interval::entire());
+        }
+
+        // `other` is `[0, upper]` or `[lower, 0]` and `self` has a sign.
+        let positive: bool = self.lower > 0.0;
+        Ok(match (positive, other.lower == 0.0) {
+            (true, true) => Interval { lower: quotient(self.lower, other.upper).0, upper: f64::INFINITY },
+            (false, true) => Interval { lower: f64::NEG_INFINITY, upper: quotient(self.upper, other.upper).1 },
+            (true, false) => Interval { lower: f64::NEG_INFINITY, upper: quotient(self.lower, other.lower).1 },
+            (false, false) => Interval { lower: quotient(self.upper, other.lower).0, upper: f64::INFINITY }
+        })
+    }
+
+    // Integer exponents are computed by repeated outward rounded products.
+    // Other exponents rely on `powf`, whose result is widened by two floats
+    // on each side, and need a non negative base.
+    fn power(self, other: Interval) -> Result<Interval, ArithmeticError> {
+        if other.lower == other.upper && other.lower.fract() == 0.0 && other.lower.abs() <= i32::MAX as f64 {
+            return self.integer_power(other.lower as i64);
+        }
+        if self.lower < 0.0 {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+        if self.lower == 0.0 && other.lower <= 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let result: Interval = Interval::hull(&[
+            (self.lower.powf(other.lower), self.lower.powf(other.lower)),
+            (self.lower.powf(other.upper), self.lower.powf(other.upper)),
+            (self.upper.powf(other.lower), self.upper.powf(other.lower)),
+            (self.upper.powf(other.upper), self.upper.powf(other.upper))
+        ]);
+        let result: Interval = widened(result.lower, result.upper);
+
+        Ok(Interval { lower: result.lower.max(0.0), upper: result.upper })
+    }
+
+    fn negate(self) -> Result<Interval, ArithmeticError> {
+        Ok(Interval { lower: -self.upper, upper: -self.lower })
+    }
+
+    // Intervals are ordered when every value of one is below every value of
+    // the other, equal when both are the same single value.
+    fn compare(&self, other: &Interval) -> Result<Ordering, ArithmeticError> {
+        if self.upper < other.lower {
+            Ok(Ordering::Less)
+        } else if self.lower > other.upper {
+            Ok(Ordering::Greater)
+        } else if self.lower == self.upper && *self == *other {
+            Ok(Ordering::Equal)
+        } else {
+            Err(ArithmeticError::Incomparable)
+        }
+    }
+
+    fn equals(&self, other: &Interval) -> Result<bool, ArithmeticError> {
+        self.compare(other).map(|ordering| ordering == Ordering::Equal)
+    }
+
+    // Smallest interval holding both bounds, themselves possibly intervals.
+    fn interval(lower: Interval, upper: Interval) -> Result<Interval, ArithmeticError> {
+        Interval::new(lower.lower, upper.upper)
+    }
+
+    fn sqrt(self) -> Result<Interval, ArithmeticError> {
+        if self.lower < 0.0 {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+
+        Ok(Interval { lower: root(self.lower).0, upper: root(self.upper).1 })
+    }
+
+    fn sin(self) -> Result<Interval, ArithmeticError> {
+        Ok(self.periodic(f64::sin, PI / 2.0))
+    }
+
+    fn cos(self) -> Result<Interval, ArithmeticError> {
+        Ok(self.periodic(f64::cos, 0.0))
+    }
+
+    // Intervals around an asymptote of `tan` give every value.
+    fn tan(self) -> Result<Interval, ArithmeticError> {
+        if (self.upper - self.lower).partial_cmp(&PI) != Some(Ordering::Less) || self.reaches(PI / 2.0, false) {
+            return Ok(Interval::entire());
+        }
+
+        Ok(widened(self.lower.tan(), self.upper.tan()))
+    }
+
+    fn ln(self) -> Result<Interval, ArithmeticError> {
+        if self.lower < 0.0 || self.upper == 0.0 {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+
+        Ok(widened(self.lower.ln(), self.upper.ln()))
+    }
+
+    fn log10(self) -> Result<Interval, ArithmeticError> {
+        if self.lower < 0.0 || self.upper == 0.0 {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+
+        Ok(widened(self.lower.log10(), self.upper.log10()))
+    }
+
+    fn exp(self) -> Result<Interval, ArithmeticError> {
+        let result: Interval = widened(self.lower.exp(), self.upper.exp());
+
+        Ok(Interval { lower: result.lower.max(0.0), upper: result.upper })
+    }
+
+    fn absolute_value(self) -> Result<Interval, ArithmeticError> {
+        if self.contains(0.0) {
+            return Ok(Interval { lower: 0.0, upper: self.upper.max(-self.lower) });
+        }
+
+        if self.upper < 0.0 { self.negate() } else { Ok(self) }
+    }
+
+    fn floor(self) -> Result<Interval, ArithmeticError> {
+        Ok(Interval { lower: self.lower.floor(), upper: self.upper.floor() })
+    }
+
+    fn ceil(self) -> Result<Interval, ArithmeticError> {
+        Ok(Interval { lower: self.lower.ceil(), upper: self.upper.ceil() })
+    }
+
+    fn round(self) -> Result<Interval, ArithmeticError> {
+        Ok(Interval { lower: self.lower.round(), upper: self.upper.round() })
+    }
+
+    fn minimum(self, other: Interval) -> Result<Interval, ArithmeticError> {
+        Ok(Interval { lower: self.lower.min(other.lower), upper: self.upper.min(other.upper) })
+    }
+
+    fn maximum(self, other: Interval) -> Result<Interval, ArithmeticError> {
+        Ok(Interval { lower: self.lower.max(other.lower), upper: self.upper.max(other.upper) })
+    }
+
+    // Only intervals reduced to an integer hold nothing but integers.
+    fn factorial(self) -> Result<Interval, ArithmeticError> {
+        if self.lower != self.upper {
+            return Err(ArithmeticError::OutOfDomain);
+        }
+
+        let count: f64 = self.lower;
+        if count < 0.0 || count.fract() != 0.0 {
+            return Err(ArithmeticError::OutOfDomain);
+        }
+
+        let mut result: Interval = Interval::from(1.0);
+        let mut factor: f64 = 2.0;
+        while factor <= count && result.lower.is_finite() {
+            result = result.product(&Interval::from(factor));
+            factor += 1.0;
+        }
+
+        Ok(if result.lower.is_finite() { result } else { Interval { lower: f64::MAX, upper: f64::INFINITY } })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(lower: f64, upper: f64) -> Interval {
+        Interval::new(lower, upper).unwrap()
+    }
+
+    #[test]
+    fn literals_are_widened_when_not_exact() {
+        let result: Vec<Interval> = ["0.5", "3", "0.1", "0x1F"]
+            .iter()
+            .map(|string| Interval::from_literal(&string.parse().unwrap(), &()).unwrap())
+            .collect();
+
+        let expected_result: Vec<Interval> = [
+            Interval::from(0.5),
+            Interval::from(3.0),
+            interval(0.1f64.next_down(), 0.1),
+            Interval::from(31.0)
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn bounds_are_rounded_outward() {
+        let result: Interval = Interval::from(0.1).plus(Interval::from(0.2)).unwrap();
+
+        assert_eq!(result, interval(0.30000000000000004f64.next_down(), 0.30000000000000004));
+        assert_eq!(Interval::from(1.0).plus(Interval::from(2.0)), Ok(Interval::from(3.0)));
+
+        let third: Interval = Interval::from(1.0).divide(Interval::from(3.0)).unwrap();
+        assert_eq!(third.upper(), third.lower().next_up());
+    }
+
+    #[test]
+    fn intervals_support_every_operator() {
+        let a: Interval = interval(1.0, 2.0);
+        let b: Interval = interval(-1.0, 4.0);
+
+        assert_eq!(a.plus(b), Ok(interval(0.0, 6.0)));
+        assert_eq!(a.minus(b), Ok(interval(-3.0, 3.0)));
+        assert_eq!(a.times(b), Ok(interval(-2.0, 8.0)));
+        assert_eq!(b.divide(interval(2.0, 4.0)), Ok(interval(-0.5, 2.0)));
+        assert_eq!(b.power(Interval::from(2.0)), Ok(interval(0.0, 16.0)));
+        assert_eq!(b.power(Interval::from(3.0)), Ok(interval(-1.0, 64.0)));
+        assert_eq!(a.power(Interval::from(-1.0)), Ok(interval(0.5, 1.0)));
+        assert_eq!(a.negate(), Ok(interval(-2.0, -1.0)));
+        assert_eq!(Interval::interval(a, b), Ok(interval(1.0, 4.0)));
+        assert_eq!(Interval::interval(b, a), Ok(interval(-1.0, 2.0)));
+        assert_eq!(Interval::interval(Interval::from(2.0), Interval::from(1.0)), Err(ArithmeticError::EmptyInterval));
+    }
+
+    #[test]
+    fn division_by_an_interval_containing_zero() {
+        let a: Interval = interval(1.0, 2.0);
+
+        assert_eq!(a.divide(interval(0.0, 4.0)), Ok(interval(0.25, f64::INFINITY)));
+        assert_eq!(a.divide(interval(-4.0, 0.0)), Ok(interval(f64::NEG_INFINITY, -0.25)));
+        assert_eq!(a.divide(interval(-1.0, 1.0)), Ok(Interval::entire()));
+        assert_eq!(interval(-1.0, 1.0).divide(interval(0.0, 1.0)), Ok(Interval::entire()));
+        assert_eq!(a.divide(Interval::from(0.0)), Err(ArithmeticError::DivisionByZero));
+    }
+
+    #[test]
+    fn non_integer_powers_need_a_non_negative_base() {
+        let result: Interval = interval(4.0, 9.0).power(Interval::from(0.5)).unwrap();
+
+        assert!(result.contains(2.0) && result.contains(3.0));
+        assert!(result.upper() - result.lower() < 1.0 + 1e-14);
+        assert_eq!(interval(-1.0, 1.0).power(Interval::from(0.5)), Err(ArithmeticError::NotRepresentable));
+    }
+
+    #[test]
+    fn elementary_functions_enclose_the_exact_range() {
+        let two: Interval = Interval::from(2.0).sqrt().unwrap();
+        assert!(two.lower() < 2f64.sqrt() && two.upper() == two.lower().next_up());
+        assert_eq!(interval(4.0, 9.0).sqrt(), Ok(interval(2.0, 3.0)));
+        assert_eq!(interval(-1.0, 4.0).sqrt(), Err(ArithmeticError::NotRepresentable));
+
+        assert_eq!(interval(0.0, 5.0).sin(), Ok(interval(-1.0, 1.0)));
+        assert_eq!(interval(0.0, 4.0).sin().map(|result| result.lower() < 4f64.sin()), Ok(true));
+        assert_eq!(interval(-1.0, 1.0).cos().map(|result| result.upper()), Ok(1.0));
+        assert!(interval(1.0, 2.0).cos().unwrap().contains(2f64.cos()));
+        assert!(interval(0.5, 1.0).sin().unwrap().upper() < 1.0);
+        assert_eq!(interval(1.0, 2.0).tan(), Ok(Interval::entire()));
+
+        let result: Interval = interval(0.0, 1.0).exp().unwrap();
+        assert!(result.contains(1.0) && result.contains(std::f64::consts::E));
+        assert_eq!(interval(0.0, 1.0).ln().map(|result| result.lower()), Ok(f64::NEG_INFINITY));
+        assert_eq!(interval(-1.0, 1.0).ln(), Err(ArithmeticError::NotRepresentable));
+    }
+
+    #[test]
+    fn rounding_and_extrema_apply_to_each_bound() {
+        let a: Interval = interval(-1.5, 2.5);
+        let b: Interval = interval(0.0, 1.0);
+
+        assert_eq!(a.absolute_value(), Ok(interval(0.0, 2.5)));
+        assert_eq!(a.floor(), Ok(interval(-2.0, 2.0)));
+        assert_eq!(a.ceil(), Ok(interval(-1.0, 3.0)));
+        assert_eq!(a.round(), Ok(interval(-2.0, 3.0)));
+        assert_eq!(a.minimum(b), Ok(interval(-1.5, 1.0)));
+        assert_eq!(a.maximum(b), Ok(interval(0.0, 2.5)));
+    }
+
+    #[test]
+    fn integer_division_remainder_and_factorial_enclose_the_exact_result() {
+        assert_eq!(Interval::from(7.0).integer_divide(Interval::from(2.0)), Ok(Interval::from(3.0)));
+        assert_eq!(Interval::from(-7.0).remainder(Interval::from(2.0)), Ok(Interval::from(1.0)));
+        assert_eq!(interval(7.0, 8.0).integer_divide(Interval::from(2.0)), Ok(interval(3.0, 4.0)));
+        assert_eq!(Interval::from(5.0).factorial(), Ok(Interval::from(120.0)));
+        assert_eq!(Interval::from(171.0).factorial(), Ok(interval(f64::MAX, f64::INFINITY)));
+        assert_eq!(interval(4.0, 5.0).factorial(), Err(ArithmeticError::OutOfDomain));
+    }
+
+    #[test]
+    fn intervals_are_displayed_with_their_bounds() {
+        assert_eq!(interval(1.5, 2.0).to_string(), "[1.5, 2]");
+    }
+}
@@ -0,0 +1,276 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display, Formatter, Error};
+
+use self::constant::Constant;
+use self::tolerance::Tolerance;
+use tokenizer::token::literal::Literal;
+use tokenizer::token::literal::digit::Digit;
+
+pub mod big_integer;
+pub mod complex;
+pub mod constant;
+pub mod decimal;
+pub mod float;
+pub mod integer;
+pub mod interval;
+pub mod rational;
+pub mod tolerance;
+pub mod uncertain;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArithmeticError {
+    DivisionByZero,
+    Overflow,
+    // The value exists but the number type cannot hold it, e.g. `1.5` or
+    // `2^-1` with integers.
+    NotRepresentable,
+    // An interval whose lower bound is above its upper bound.
+    EmptyInterval,
+    // The operation is not defined for its operand, e.g. `(-1)!`.
+    OutOfDomain,
+    // Values without a known order, such as `1+2i` and `2`, or overlapping
+    // intervals.
+    Incomparable
+}
+
+impl Display for ArithmeticError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            ArithmeticError::DivisionByZero => write!(f, "division by zero"),
+            ArithmeticError::Overflow => write!(f, "overflow"),
+            ArithmeticError::NotRepresentable => write!(f, "value not representable by the number type"),
+            ArithmeticError::EmptyInterval => write!(f, "empty interval"),
+            ArithmeticError::OutOfDomain => write!(f, "value out of the operation's domain"),
+            ArithmeticError::Incomparable => write!(f, "values cannot be compared")
+        }
+    }
+}
+
+// Digits of an integer written in decimal, and the power of ten multiplying
+// them: `1.5e1` is `15 * 10^0`, `100e-2` is `1 * 10^0` and `2e3` is
+// `2 * 10^3`. Literals whose point or negative exponent drops other digits
+// than zeros are not integers.
+pub(crate) fn integer_digits(literal: &Literal) -> Result<(Vec<Digit>, u64), ArithmeticError> {
+    let fraction: &[Digit] = literal.fraction().unwrap_or(&[]);
+    let mut digits: Vec<Digit> = literal.integer().iter().chain(fraction).cloned().collect();
+    let zeros: usize = digits.iter().rev().take_while(|digit| **digit == Digit::Zero).count();
+    digits.truncate(digits.len() - zeros);
+    if digits.is_empty() { return Ok((digits, 0)); }
+
+    let mut power: i128 = zeros as i128 - fraction.len() as i128;
+    if let Some(exponent) = literal.exponent() {
+        let magnitude: Option<u64> = exponent.digits.iter().try_fold(0u64, |value, digit| {
+            value.checked_mul(10).and_then(|value| value.checked_add(digit.value() as u64))
+        });
+        power = match (magnitude, exponent.negative) {
+            (Some(magnitude), true) => power - magnitude as i128,
+            (Some(magnitude), false) => power + magnitude as i128,
+            (None, true) => return Err(ArithmeticError::NotRepresentable),
+            (None, false) => return Err(ArithmeticError::Overflow)
+        };
+    }
+    if power < 0 { return Err(ArithmeticError::NotRepresentable); }
+
+    u64::try_from(power).map(|power| (digits, power)).map_err(|_| ArithmeticError::Overflow)
+}
+
+// Values the interpreter computes with. Each operation is named after the
+// operator it implements and reports what makes it fail instead of
+// panicking or wrapping. Operations a number type leaves out fail with
+// `NotRepresentable`.
+pub trait Number: Clone + Debug + Display + PartialEq {
+    // Parameters of the number type, such as a precision, given when the
+    // values are created from literals. `()` when there is none.
+    type Settings: Clone + Debug + Default;
+
+    fn from_literal(literal: &Literal, settings: &Self::Settings) -> Result<Self, ArithmeticError>;
+
+    // Closest value to `constant`, by default read from its decimal digits,
+    // which are more than enough for floats.
+    fn constant(constant: Constant, settings: &Self::Settings) -> Result<Self, ArithmeticError> {
+        Self::from_literal(&constant.literal(), settings)
+    }
+
+    fn plus(self, other: Self) -> Result<Self, ArithmeticError>;
+    fn minus(self, other: Self) -> Result<Self, ArithmeticError>;
+    fn times(self, other: Self) -> Result<Self, ArithmeticError>;
+    fn divide(self, other: Self) -> Result<Self, ArithmeticError>;
+    fn power(self, other: Self) -> Result<Self, ArithmeticError>;
+    fn negate(self) -> Result<Self, ArithmeticError>;
+
+    // Order of `self` relative to `other`, for the comparison operators.
+    fn compare(&self, _other: &Self) -> Result<Ordering, ArithmeticError> {
+        Err(ArithmeticError::Incomparable)
+    }
+
+    // Whether both values are equal, for `==` and `!=`. Fails when this is
+    // not certain.
+    fn equals(&self, other: &Self) -> Result<bool, ArithmeticError> {
+        Ok(self == other)
+    }
+
+    // Whether both values are within `tolerance` of each other, for `~=`.
+    // The tolerance is read as a literal, so number types unable to
+    // represent it have to override this.
+    fn approximately_equals(&self, other: &Self, tolerance: &Tolerance, settings: &Self::Settings) -> Result<bool, ArithmeticError> {
+        let relative: Self = Self::from_literal(&tolerance.relative_literal()?, settings)?;
+        let absolute: Self = Self::from_literal(&tolerance.absolute_literal()?, settings)?;
+
+        let difference: Self = self.clone().minus(other.clone())?.absolute_value()?;
+        let magnitude: Self = self.clone().absolute_value()?.maximum(other.clone().absolute_value()?)?;
+        let bound: Self = relative.times(magnitude)?.maximum(absolute)?;
+
+        Ok(difference.compare(&bound)? != Ordering::Greater)
+    }
+
+    // Quotient rounded down, so that `a == b * (a // b) + a % b` and the
+    // remainder has the sign of the divisor, or is zero: `-7 % 2` is `1` and
+    // `7 % -2` is `-1`.
+    fn integer_divide(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.divide(other)?.floor()
+    }
+
+    fn remainder(self, other: Self) -> Result<Self, ArithmeticError> {
+        let quotient: Self = self.clone().integer_divide(other.clone())?;
+
+        self.minus(other.times(quotient)?)
+    }
+
+    // Product of the integers from 1 to `self`, only defined when `self` is
+    // a non negative integer.
+    fn factorial(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    // Bitwise operators, acting on the two's complement representation of
+    // integers only.
+    fn bit_and(self, _other: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn bit_or(self, _other: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn bit_xor(self, _other: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn bit_not(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    // Multiplies `self` by 2 to the power of `amount`, a non negative
+    // integer, failing with `Overflow` rather than losing bits.
+    fn shift_left(self, _amount: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    // Divides `self` by 2 to the power of `amount`, rounding down as an
+    // arithmetic shift does: `-5 >> 1` is `-3`.
+    fn shift_right(self, _amount: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    // Elementary functions, failing by default for number types that
+    // cannot represent their results.
+    fn sqrt(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn sin(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn cos(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn tan(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn ln(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn log10(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn exp(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn absolute_value(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn floor(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn ceil(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    // Ties are rounded away from zero.
+    fn round(self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn minimum(self, _other: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn maximum(self, _other: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    // Value of `value ± error`, only meaningful to number types carrying an
+    // uncertainty.
+    fn plus_minus(self, _error: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    // Value of `[lower, upper]`, only meaningful to number types holding
+    // ranges of values.
+    fn interval(_lower: Self, _upper: Self) -> Result<Self, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_arithmetic_error_has_a_human_readable_message() {
+        let result: Vec<String> = [
+            ArithmeticError::DivisionByZero,
+            ArithmeticError::Overflow,
+            ArithmeticError::NotRepresentable,
+            ArithmeticError::EmptyInterval,
+            ArithmeticError::OutOfDomain,
+            ArithmeticError::Incomparable
+        ]
+            .iter()
+            .map(|error| error.to_string())
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "division by zero",
+            "overflow",
+            "value not representable by the number type",
+            "empty interval",
+            "value out of the operation's domain",
+            "values cannot be compared"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+}
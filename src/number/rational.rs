@@ -0,0 +1,469 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Error};
+
+use super::{ArithmeticError, Number};
+use super::big_integer::BigInteger;
+use super::constant::Constant;
+use tokenizer::token::literal::Literal;
+use tokenizer::token::literal::radix::Radix;
+
+// Exact fraction, always reduced and with a positive denominator so that
+// equal values have equal representations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rational {
+    numerator: BigInteger,
+    denominator: BigInteger
+}
+
+fn gcd(first: &BigInteger, second: &BigInteger) -> BigInteger {
+    let mut first: BigInteger = first.abs();
+    let mut second: BigInteger = second.abs();
+
+    while !second.is_zero() {
+        let (_, remainder) = first.div_rem(&second).unwrap();
+        first = second;
+        second = remainder;
+    }
+
+    first
+}
+
+impl Rational {
+    pub fn new(numerator: BigInteger, denominator: BigInteger) -> Result<Rational, ArithmeticError> {
+        if denominator.is_zero() { return Err(ArithmeticError::DivisionByZero); }
+
+        let divisor: BigInteger = gcd(&numerator, &denominator);
+        let divisor: BigInteger = if denominator.is_negative() { -&divisor } else { divisor };
+        let (numerator, _) = numerator.div_rem(&divisor)?;
+        let (denominator, _) = denominator.div_rem(&divisor)?;
+
+        Ok(Rational { numerator, denominator })
+    }
+
+    pub fn numerator(&self) -> &BigInteger {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &BigInteger {
+        &self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == BigInteger::from(1)
+    }
+
+    // Displays as a mixed number: `4/3` becomes `1 1/3`.
+    pub fn mixed(&self) -> Mixed<'_> {
+        Mixed(self)
+    }
+
+    // Exact value of a finite float, as `mantissa * 2^exponent`.
+    pub fn from_f64(value: f64) -> Result<Rational, ArithmeticError> {
+        if !value.is_finite() { return Err(ArithmeticError::NotRepresentable); }
+
+        let bits: u64 = value.to_bits();
+        let biased_exponent: i64 = ((bits >> 52) & 0x7ff) as i64;
+        let fraction: i64 = (bits & ((1 << 52) - 1)) as i64;
+        let (mantissa, exponent): (i64, i64) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased_exponent - 1075)
+        };
+        let mantissa: BigInteger = BigInteger::from(if value < 0.0 { -mantissa } else { mantissa });
+        let power: BigInteger = BigInteger::from(2).pow(exponent.unsigned_abs());
+
+        if exponent >= 0 {
+            Ok(Rational::from(&mantissa * &power))
+        } else {
+            Rational::new(mantissa, power)
+        }
+    }
+
+    // Nearest float, through the decimal expansion of both terms.
+    pub fn to_f64(&self) -> f64 {
+        let parse = |integer: &BigInteger| integer.to_string().parse::<f64>().unwrap_or(f64::NAN);
+
+        parse(&self.numerator) / parse(&self.denominator)
+    }
+
+    // Integer quotient, rounded toward zero, and the remainder of the
+    // division of the numerator by the denominator.
+    fn integer_part(&self) -> (BigInteger, BigInteger) {
+        self.numerator.div_rem(&self.denominator).unwrap()
+    }
+
+    fn recip(&self) -> Result<Rational, ArithmeticError> {
+        Rational::new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    // Applies the integer `operation`, for the bitwise operators.
+    fn integer_operation(
+        self,
+        other: Rational,
+        operation: fn(BigInteger, BigInteger) -> Result<BigInteger, ArithmeticError>
+    ) -> Result<Rational, ArithmeticError> {
+        if !self.is_integer() || !other.is_integer() { return Err(ArithmeticError::OutOfDomain); }
+
+        operation(self.numerator, other.numerator).map(Rational::from)
+    }
+}
+
+impl From<BigInteger> for Rational {
+    fn from(integer: BigInteger) -> Rational {
+        Rational { numerator: integer, denominator: BigInteger::from(1) }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(integer: i64) -> Rational {
+        Rational::from(BigInteger::from(integer))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if self.is_integer() { return write!(f, "{}", self.numerator); }
+
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+pub struct Mixed<'a>(&'a Rational);
+
+impl<'a> Display for Mixed<'a> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (whole, remainder) = self.0.numerator.div_rem(&self.0.denominator).unwrap();
+
+        if whole.is_zero() || remainder.is_zero() { return write!(f, "{}", self.0); }
+
+        write!(f, "{} {}/{}", whole, remainder.abs(), self.0.denominator)
+    }
+}
+
+impl Number for Rational {
+    type Settings = ();
+
+    fn from_literal(literal: &Literal, _settings: &()) -> Result<Rational, ArithmeticError> {
+        if literal.is_imaginary() {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+        if literal.radix() != Radix::Decimal {
+            return BigInteger::from_literal(literal, &()).map(Rational::from);
+        }
+
+        // `12.34e-5` is `1234 / 10^2 / 10^5`.
+        let fraction: &[_] = literal.fraction().unwrap_or(&[]);
+        let digits: Vec<_> = literal.integer().iter().chain(fraction).cloned().collect();
+        let ten: BigInteger = BigInteger::from(10);
+        let mut value: Rational = Rational::new(
+            BigInteger::from_digits(false, &digits),
+            ten.checked_pow(fraction.len() as u64)?
+        )?;
+
+        if let Some(exponent) = literal.exponent() {
+            let scale: u64 = BigInteger::from_digits(false, &exponent.digits)
+                .to_u64()
+                .ok_or(ArithmeticError::Overflow)?;
+            let scale: Rational = Rational::from(ten.checked_pow(scale)?);
+
+            value = if exponent.negative { value.divide(scale)? } else { value.times(scale)? };
+        }
+
+        Ok(value)
+    }
+
+    // No constant is rational.
+    fn constant(_constant: Constant, _settings: &()) -> Result<Rational, ArithmeticError> {
+        Err(ArithmeticError::NotRepresentable)
+    }
+
+    fn plus(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        Rational::new(
+            &(&self.numerator * &other.denominator) + &(&other.numerator * &self.denominator),
+            &self.denominator * &other.denominator
+        )
+    }
+
+    fn minus(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        self.plus(other.negate()?)
+    }
+
+    fn times(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        Rational::new(&self.numerator * &other.numerator, &self.denominator * &other.denominator)
+    }
+
+    fn divide(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        self.times(other.recip()?)
+    }
+
+    // Only integer exponents keep the result rational.
+    fn power(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        if !other.is_integer() { return Err(ArithmeticError::NotRepresentable); }
+
+        let base: Rational = if other.numerator.is_negative() { self.recip()? } else { self };
+        let numerator: BigInteger = base.numerator.power(other.numerator.abs())?;
+        let denominator: BigInteger = base.denominator.power(other.numerator.abs())?;
+
+        Rational::new(numerator, denominator)
+    }
+
+    fn negate(self) -> Result<Rational, ArithmeticError> {
+        Ok(Rational { numerator: -&self.numerator, denominator: self.denominator })
+    }
+
+    fn compare(&self, other: &Rational) -> Result<Ordering, ArithmeticError> {
+        Ok(self.cmp(other))
+    }
+
+    // Only squares of rationals have a rational root.
+    fn sqrt(self) -> Result<Rational, ArithmeticError> {
+        let root = |integer: &BigInteger| integer.integer_sqrt().filter(|root| &(root * root) == integer);
+
+        match (root(&self.numerator), root(&self.denominator)) {
+            (Some(numerator), Some(denominator)) => Rational::new(numerator, denominator),
+            _ => Err(ArithmeticError::NotRepresentable)
+        }
+    }
+
+    fn absolute_value(self) -> Result<Rational, ArithmeticError> {
+        Ok(Rational { numerator: self.numerator.abs(), denominator: self.denominator })
+    }
+
+    fn floor(self) -> Result<Rational, ArithmeticError> {
+        let (quotient, remainder) = self.integer_part();
+        let step: i64 = if remainder.is_negative() { -1 } else { 0 };
+
+        Ok(Rational::from(&quotient + &BigInteger::from(step)))
+    }
+
+    fn ceil(self) -> Result<Rational, ArithmeticError> {
+        let (quotient, remainder) = self.integer_part();
+        let step: i64 = if !remainder.is_negative() && !remainder.is_zero() { 1 } else { 0 };
+
+        Ok(Rational::from(&quotient + &BigInteger::from(step)))
+    }
+
+    fn round(self) -> Result<Rational, ArithmeticError> {
+        let (quotient, remainder) = self.integer_part();
+        let twice_remainder: BigInteger = &remainder.abs() * &BigInteger::from(2);
+        let step: i64 = match (twice_remainder >= self.denominator, remainder.is_negative()) {
+            (true, true) => -1,
+            (true, false) => 1,
+            (false, _) => 0
+        };
+
+        Ok(Rational::from(&quotient + &BigInteger::from(step)))
+    }
+
+    fn minimum(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        Ok(Ord::min(self, other))
+    }
+
+    fn maximum(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        Ok(Ord::max(self, other))
+    }
+
+    fn factorial(self) -> Result<Rational, ArithmeticError> {
+        if !self.is_integer() { return Err(ArithmeticError::OutOfDomain); }
+
+        self.numerator.factorial().map(Rational::from)
+    }
+
+    fn bit_and(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        self.integer_operation(other, BigInteger::bit_and)
+    }
+
+    fn bit_or(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        self.integer_operation(other, BigInteger::bit_or)
+    }
+
+    fn bit_xor(self, other: Rational) -> Result<Rational, ArithmeticError> {
+        self.integer_operation(other, BigInteger::bit_xor)
+    }
+
+    fn bit_not(self) -> Result<Rational, ArithmeticError> {
+        if !self.is_integer() { return Err(ArithmeticError::OutOfDomain); }
+
+        self.numerator.bit_not().map(Rational::from)
+    }
+
+    fn shift_left(self, amount: Rational) -> Result<Rational, ArithmeticError> {
+        self.integer_operation(amount, BigInteger::shift_left)
+    }
+
+    fn shift_right(self, amount: Rational) -> Result<Rational, ArithmeticError> {
+        self.integer_operation(amount, BigInteger::shift_right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(numerator: i64, denominator: i64) -> Rational {
+        Rational::new(BigInteger::from(numerator), BigInteger::from(denominator)).unwrap()
+    }
+
+    #[test]
+    fn rationals_are_always_reduced() {
+        let result: Vec<String> = [rational(2, 4), rational(6, -4), rational(0, -5), rational(10, 5)]
+            .iter()
+            .map(|value| value.to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1/2", "-3/2", "0", "2"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rationals_can_be_instanciated_from_literals_exactly() {
+        let result: Vec<Rational> = ["0.1", "12.34e-1", "1.5e3", "0x10"]
+            .iter()
+            .map(|string| Rational::from_literal(&string.parse().unwrap(), &()).unwrap())
+            .collect();
+
+        let expected_result: [Rational; 4] = [rational(1, 10), rational(617, 500), rational(1500, 1), rational(16, 1)];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rationals_reject_literals_of_too_many_digits() {
+        let mut result: Vec<Result<Rational, ArithmeticError>> = ["1e-999999999", "1e999999999", "1e-10000"]
+            .iter()
+            .map(|string| Rational::from_literal(&string.parse().unwrap(), &()))
+            .collect();
+        result.push(rational(2, 1).power(rational(99_999_999_999, 1)));
+
+        let expected_result: Vec<Result<Rational, ArithmeticError>> = vec![Err(ArithmeticError::Overflow); 4];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rational_arithmetic_is_exact() {
+        let result: Vec<Rational> = [
+            rational(1, 3).times(rational(3, 1)),
+            rational(1, 10).plus(rational(2, 10)),
+            rational(1, 2).minus(rational(1, 3)),
+            rational(1, 1).divide(rational(3, 1)),
+            rational(2, 3).power(rational(-2, 1))
+        ]
+            .iter()
+            .map(|result| result.clone().unwrap())
+            .collect();
+
+        let expected_result: [Rational; 5] = [
+            rational(1, 1),
+            rational(3, 10),
+            rational(1, 6),
+            rational(1, 3),
+            rational(9, 4)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rationals_reject_division_by_zero_and_irrational_powers() {
+        let result: Vec<Result<Rational, ArithmeticError>> = [
+            rational(1, 1).divide(rational(0, 1)),
+            rational(2, 1).power(rational(1, 2))
+        ].to_vec();
+
+        let expected_result: [Result<Rational, ArithmeticError>; 2] = [
+            Err(ArithmeticError::DivisionByZero),
+            Err(ArithmeticError::NotRepresentable)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rationals_can_be_displayed_as_mixed_numbers() {
+        let result: Vec<String> = [rational(4, 3), rational(-7, 2), rational(1, 2), rational(3, 1)]
+            .iter()
+            .map(|value| value.mixed().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1 1/3", "-3 1/2", "1/2", "3"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rational_functions_stay_exact() {
+        let result: Vec<Result<Rational, ArithmeticError>> = [
+            rational(9, 4).sqrt(),
+            rational(2, 1).sqrt(),
+            rational(-7, 2).floor(),
+            rational(-7, 2).ceil(),
+            rational(-7, 2).round(),
+            rational(7, 3).round(),
+            rational(-7, 2).absolute_value(),
+            rational(1, 3).maximum(rational(1, 2)),
+            rational(1, 1).exp()
+        ].to_vec();
+
+        let expected_result: [Result<Rational, ArithmeticError>; 9] = [
+            Ok(rational(3, 2)),
+            Err(ArithmeticError::NotRepresentable),
+            Ok(rational(-4, 1)),
+            Ok(rational(-3, 1)),
+            Ok(rational(-4, 1)),
+            Ok(rational(2, 1)),
+            Ok(rational(7, 2)),
+            Ok(rational(1, 2)),
+            Err(ArithmeticError::NotRepresentable)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rational_integer_division_and_remainder_are_exact() {
+        assert_eq!(rational(7, 2).integer_divide(rational(1, 3)), Ok(rational(10, 1)));
+        assert_eq!(rational(-7, 2).remainder(rational(1, 3)), Ok(rational(1, 6)));
+        assert_eq!(rational(7, 2).remainder(rational(-1, 3)), Ok(rational(-1, 6)));
+        assert_eq!(rational(1, 1).remainder(rational(0, 1)), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(rational(5, 1).factorial(), Ok(rational(120, 1)));
+        assert_eq!(rational(5, 2).factorial(), Err(ArithmeticError::OutOfDomain));
+    }
+
+    #[test]
+    fn rational_bitwise_operators_reject_fractions() {
+        assert_eq!(rational(12, 1).bit_and(rational(10, 1)), Ok(rational(8, 1)));
+        assert_eq!(rational(-5, 1).shift_right(rational(1, 1)), Ok(rational(-3, 1)));
+        assert_eq!(rational(5, 1).bit_not(), Ok(rational(-6, 1)));
+        assert_eq!(rational(5, 2).bit_or(rational(1, 1)), Err(ArithmeticError::OutOfDomain));
+        assert_eq!(rational(1, 1).shift_left(rational(1, 2)), Err(ArithmeticError::OutOfDomain));
+    }
+
+    #[test]
+    fn rationals_convert_exactly_from_floats() {
+        assert_eq!(Rational::from_f64(-0.375), Ok(rational(-3, 8)));
+        assert_eq!(Rational::from_f64(1e300).map(|value| value.is_integer()), Ok(true));
+        assert_eq!(Rational::from_f64(0.1).map(|value| value == rational(1, 10)), Ok(false));
+        assert_eq!(Rational::from_f64(f64::NAN), Err(ArithmeticError::NotRepresentable));
+        assert_eq!(rational(1, 3).to_f64(), 1.0 / 3.0);
+    }
+}
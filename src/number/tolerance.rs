@@ -0,0 +1,67 @@
+use super::ArithmeticError;
+use tokenizer::token::literal::Literal;
+
+// How far apart two values may be for `~=` to hold: no further than
+// `relative` times the larger magnitude, or than `absolute`, whichever is
+// larger. The absolute part is what lets `sin(pi) ~= 0` hold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+    pub relative: f64,
+    pub absolute: f64
+}
+
+fn literal(value: f64) -> Result<Literal, ArithmeticError> {
+    format!("{:e}", value).parse().map_err(|_| ArithmeticError::NotRepresentable)
+}
+
+impl Tolerance {
+    // Largest difference allowed between two values of the given magnitude.
+    pub fn bound(&self, magnitude: f64) -> f64 {
+        (self.relative * magnitude).max(self.absolute)
+    }
+
+    // Literals for the number types to read the tolerance with their own
+    // precision.
+    pub fn relative_literal(&self) -> Result<Literal, ArithmeticError> {
+        literal(self.relative)
+    }
+
+    pub fn absolute_literal(&self) -> Result<Literal, ArithmeticError> {
+        literal(self.absolute)
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Tolerance {
+        Tolerance { relative: 1e-9, absolute: 1e-12 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_bound_is_the_larger_of_both_tolerances() {
+        let tolerance: Tolerance = Tolerance { relative: 1e-3, absolute: 1e-2 };
+
+        assert_eq!(tolerance.bound(1.0), 1e-2);
+        assert_eq!(tolerance.bound(100.0), 1e-1);
+    }
+
+    #[test]
+    fn tolerances_are_written_as_literals() {
+        let result: Vec<String> = [Tolerance::default().relative_literal(), Tolerance::default().absolute_literal()]
+            .iter()
+            .map(|literal| literal.clone().unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1e-9", "1e-12"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+        assert_eq!(literal(f64::NAN), Err(ArithmeticError::NotRepresentable));
+    }
+}
@@ -0,0 +1,287 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Error};
+
+use super::{ArithmeticError, Number};
+use super::tolerance::Tolerance;
+use tokenizer::token::literal::Literal;
+
+// Measured value with its standard uncertainty. Operations propagate the
+// uncertainties to first order, assuming the operands are independent: the
+// uncertainty of `f(a, b)` is the quadratic sum of `∂f/∂a σa` and `∂f/∂b σb`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uncertain {
+    value: f64,
+    uncertainty: f64
+}
+
+impl Uncertain {
+    pub fn new(value: f64, uncertainty: f64) -> Uncertain {
+        Uncertain { value, uncertainty: uncertainty.abs() }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn uncertainty(&self) -> f64 {
+        self.uncertainty
+    }
+
+    // Result of a function of one operand, given its value and derivative
+    // at `self`.
+    pub fn map(&self, value: f64, derivative: f64) -> Result<Uncertain, ArithmeticError> {
+        propagate(value, &[(derivative, self.uncertainty)])
+    }
+}
+
+// Quadratic sum of each partial derivative times the uncertainty of the
+// corresponding operand. Exact operands contribute nothing, even where the
+// derivative is not defined.
+fn propagate(value: f64, terms: &[(f64, f64)]) -> Result<Uncertain, ArithmeticError> {
+    let uncertainty: f64 = terms
+        .iter()
+        .filter(|&&(_, uncertainty)| uncertainty != 0.0)
+        .fold(0.0, |sum: f64, &(derivative, uncertainty)| sum.hypot(derivative * uncertainty));
+
+    if value.is_nan() || uncertainty.is_nan() {
+        return Err(ArithmeticError::NotRepresentable);
+    }
+
+    Ok(Uncertain::new(value, uncertainty))
+}
+
+impl From<f64> for Uncertain {
+    fn from(value: f64) -> Uncertain {
+        Uncertain::new(value, 0.0)
+    }
+}
+
+// The uncertainty is rounded to two significant digits, and the value to
+// the same decimal place.
+impl Display for Uncertain {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if self.uncertainty == 0.0 || !self.uncertainty.is_finite() {
+            return write!(f, "{} ± {}", self.value, self.uncertainty);
+        }
+
+        let precision: usize = (1 - self.uncertainty.log10().floor() as i32).max(0) as usize;
+        write!(f, "{:.*} ± {:.*}", precision, self.value, precision, self.uncertainty)
+    }
+}
+
+impl Number for Uncertain {
+    type Settings = ();
+
+    fn from_literal(literal: &Literal, _settings: &()) -> Result<Uncertain, ArithmeticError> {
+        f64::from_literal(literal, &()).map(Uncertain::from)
+    }
+
+    fn plus(self, other: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        propagate(self.value + other.value, &[(1.0, self.uncertainty), (1.0, other.uncertainty)])
+    }
+
+    fn minus(self, other: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        propagate(self.value - other.value, &[(1.0, self.uncertainty), (-1.0, other.uncertainty)])
+    }
+
+    fn times(self, other: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        propagate(
+            self.value * other.value,
+            &[(other.value, self.uncertainty), (self.value, other.uncertainty)]
+        )
+    }
+
+    fn divide(self, other: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        if other.value == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let value: f64 = self.value / other.value;
+        propagate(value, &[(1.0 / other.value, self.uncertainty), (-value / other.value, other.uncertainty)])
+    }
+
+    fn power(self, other: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        let value: f64 = self.value.powf(other.value);
+
+        propagate(value, &[
+            (other.value * self.value.powf(other.value - 1.0), self.uncertainty),
+            (value * self.value.ln(), other.uncertainty)
+        ])
+    }
+
+    fn negate(self) -> Result<Uncertain, ArithmeticError> {
+        Ok(Uncertain::new(-self.value, self.uncertainty))
+    }
+
+    // Comparisons only look at the measured values, whatever their
+    // uncertainties.
+    fn compare(&self, other: &Uncertain) -> Result<Ordering, ArithmeticError> {
+        self.value.partial_cmp(&other.value).ok_or(ArithmeticError::Incomparable)
+    }
+
+    fn equals(&self, other: &Uncertain) -> Result<bool, ArithmeticError> {
+        Ok(self.value == other.value)
+    }
+
+    fn approximately_equals(&self, other: &Uncertain, tolerance: &Tolerance, _settings: &()) -> Result<bool, ArithmeticError> {
+        self.value.approximately_equals(&other.value, tolerance, &())
+    }
+
+    // Adds `error` to the uncertainty of `self`, as another independent
+    // source of error. The error itself has to be exact.
+    fn plus_minus(self, error: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        if error.uncertainty != 0.0 {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+
+        Ok(Uncertain::new(self.value, self.uncertainty.hypot(error.value)))
+    }
+
+    fn sqrt(self) -> Result<Uncertain, ArithmeticError> {
+        let value: f64 = self.value.sqrt();
+
+        self.map(value, 0.5 / value)
+    }
+
+    fn sin(self) -> Result<Uncertain, ArithmeticError> {
+        self.map(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Result<Uncertain, ArithmeticError> {
+        self.map(self.value.cos(), -self.value.sin())
+    }
+
+    fn tan(self) -> Result<Uncertain, ArithmeticError> {
+        let value: f64 = self.value.tan();
+
+        self.map(value, 1.0 + value * value)
+    }
+
+    fn ln(self) -> Result<Uncertain, ArithmeticError> {
+        self.map(self.value.ln(), 1.0 / self.value)
+    }
+
+    fn log10(self) -> Result<Uncertain, ArithmeticError> {
+        self.map(self.value.log10(), 1.0 / (self.value * std::f64::consts::LN_10))
+    }
+
+    fn exp(self) -> Result<Uncertain, ArithmeticError> {
+        let value: f64 = self.value.exp();
+
+        self.map(value, value)
+    }
+
+    fn absolute_value(self) -> Result<Uncertain, ArithmeticError> {
+        Ok(Uncertain::new(self.value.abs(), self.uncertainty))
+    }
+
+    // Rounding functions are flat almost everywhere, so that their results
+    // are exact.
+    fn floor(self) -> Result<Uncertain, ArithmeticError> {
+        self.map(self.value.floor(), 0.0)
+    }
+
+    fn ceil(self) -> Result<Uncertain, ArithmeticError> {
+        self.map(self.value.ceil(), 0.0)
+    }
+
+    fn round(self) -> Result<Uncertain, ArithmeticError> {
+        self.map(self.value.round(), 0.0)
+    }
+
+    // The operand with the extreme value is kept, with its uncertainty.
+    fn minimum(self, other: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        Ok(if other.value < self.value { other } else { self })
+    }
+
+    fn maximum(self, other: Uncertain) -> Result<Uncertain, ArithmeticError> {
+        Ok(if other.value > self.value { other } else { self })
+    }
+
+    // The derivative of the factorial is not available, so only exact values
+    // are supported.
+    fn factorial(self) -> Result<Uncertain, ArithmeticError> {
+        if self.uncertainty != 0.0 {
+            return Err(ArithmeticError::NotRepresentable);
+        }
+
+        self.value.factorial().map(Uncertain::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uncertainties_are_propagated_through_every_operator() {
+        let a: Uncertain = Uncertain::new(3.0, 3.0);
+        let b: Uncertain = Uncertain::new(4.0, 4.0);
+
+        assert_eq!(a.plus(b), Ok(Uncertain::new(7.0, 5.0)));
+        assert_eq!(a.minus(b), Ok(Uncertain::new(-1.0, 5.0)));
+        assert_eq!(a.times(b), Ok(Uncertain::new(12.0, 12f64.hypot(12.0))));
+        assert_eq!(b.divide(Uncertain::from(2.0)), Ok(Uncertain::new(2.0, 2.0)));
+        assert_eq!(a.power(Uncertain::from(2.0)), Ok(Uncertain::new(9.0, 18.0)));
+        assert_eq!(a.negate(), Ok(Uncertain::new(-3.0, 3.0)));
+        assert_eq!(a.divide(Uncertain::from(0.0)), Err(ArithmeticError::DivisionByZero));
+    }
+
+    #[test]
+    fn exact_exponents_of_negative_values_have_no_logarithm_term() {
+        let result: Result<Uncertain, ArithmeticError> = Uncertain::new(-2.0, 1.0).power(Uncertain::from(3.0));
+
+        assert_eq!(result, Ok(Uncertain::new(-8.0, 12.0)));
+    }
+
+    #[test]
+    fn errors_are_combined_with_existing_uncertainties() {
+        let result: Result<Uncertain, ArithmeticError> = Uncertain::from(9.81)
+            .plus_minus(Uncertain::from(3.0))
+            .and_then(|value| value.plus_minus(Uncertain::from(4.0)));
+
+        assert_eq!(result, Ok(Uncertain::new(9.81, 5.0)));
+        assert_eq!(
+            Uncertain::from(1.0).plus_minus(Uncertain::new(0.1, 0.01)),
+            Err(ArithmeticError::NotRepresentable)
+        );
+    }
+
+    #[test]
+    fn uncertainties_are_propagated_through_elementary_functions() {
+        let a: Uncertain = Uncertain::new(4.0, 0.4);
+
+        assert_eq!(a.sqrt(), Ok(Uncertain::new(2.0, 0.1)));
+        assert_eq!(Uncertain::new(0.0, 0.1).sin(), Ok(Uncertain::new(0.0, 0.1)));
+        assert_eq!(Uncertain::new(0.0, 0.1).cos(), Ok(Uncertain::new(1.0, 0.0)));
+        assert_eq!(Uncertain::new(0.0, 0.5).exp(), Ok(Uncertain::new(1.0, 0.5)));
+        assert_eq!(a.ln(), Ok(Uncertain::new(4f64.ln(), 0.1)));
+        assert_eq!(a.negate().and_then(Uncertain::absolute_value), Ok(a));
+        assert_eq!(Uncertain::new(2.5, 0.1).round(), Ok(Uncertain::from(3.0)));
+        assert_eq!(a.minimum(Uncertain::new(3.0, 1.0)), Ok(Uncertain::new(3.0, 1.0)));
+        assert_eq!(Uncertain::new(-1.0, 0.1).sqrt(), Err(ArithmeticError::NotRepresentable));
+    }
+
+    #[test]
+    fn remainders_keep_the_uncertainty_of_the_dividend() {
+        assert_eq!(Uncertain::new(7.0, 0.5).remainder(Uncertain::from(2.0)), Ok(Uncertain::new(1.0, 0.5)));
+        assert_eq!(Uncertain::new(7.0, 0.5).integer_divide(Uncertain::from(2.0)), Ok(Uncertain::from(3.0)));
+        assert_eq!(Uncertain::from(4.0).factorial(), Ok(Uncertain::from(24.0)));
+        assert_eq!(Uncertain::new(4.0, 0.1).factorial(), Err(ArithmeticError::NotRepresentable));
+    }
+
+    #[test]
+    fn uncertain_values_are_displayed_with_their_uncertainty() {
+        let result: Vec<String> = [(19.62, 0.98182), (1234.4, 12.3), (2.0, 0.0), (0.5, 0.001)]
+            .iter()
+            .map(|&(value, uncertainty)| Uncertain::new(value, uncertainty).to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["19.62 ± 0.98", "1234 ± 12", "2 ± 0", "0.5000 ± 0.0010"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+}
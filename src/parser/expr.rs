@@ -0,0 +1,348 @@
+use std::fmt::{Display, Formatter, Error};
+
+use tokenizer::span::{Span, Spanned};
+use tokenizer::token::Token;
+use tokenizer::token::literal::Literal;
+use tokenizer::token::operator::Operator;
+use tokenizer::token::unary_operator::UnaryOperator;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Number(Spanned<Literal>),
+    Variable(Spanned<String>),
+    Unary {
+        op: Spanned<UnaryOperator>,
+        operand: Box<Expr>
+    },
+    Binary {
+        op: Spanned<Operator>,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>
+    },
+    // Comparisons of the same precedence following each other, as in
+    // `0 <= x < 10`, each operand being shared by the comparisons around it.
+    Chain {
+        operands: Vec<Expr>,
+        ops: Vec<Spanned<Operator>>
+    },
+    // `name = value`, whose value is the assigned one.
+    Assignment {
+        name: Spanned<String>,
+        value: Box<Expr>
+    },
+    // `name(arguments)`, spanning up to the closing parenthesis.
+    Call {
+        name: Spanned<String>,
+        arguments: Vec<Expr>,
+        span: Span
+    },
+    // `name(parameters) = body`, which has no value.
+    Definition {
+        name: Spanned<String>,
+        parameters: Vec<Spanned<String>>,
+        body: Box<Expr>
+    },
+    // Statements evaluated in order, whose value is the last one's.
+    Sequence(Vec<Expr>),
+    // `[lower, upper]`, spanning from one bracket to the other.
+    Interval {
+        lower: Box<Expr>,
+        upper: Box<Expr>,
+        span: Span
+    },
+    // `condition ? then : otherwise`, evaluating only one of the values.
+    Conditional {
+        condition: Box<Expr>,
+        then: Box<Expr>,
+        otherwise: Box<Expr>
+    }
+}
+
+impl Expr {
+    pub fn span(&self) -> Span {
+        match *self {
+            Expr::Number(ref literal) => literal.span,
+            Expr::Variable(ref name) => name.span,
+            Expr::Unary { ref op, ref operand } if op.node.is_postfix() => operand.span().to(&op.span),
+            Expr::Unary { ref op, ref operand } => op.span.to(&operand.span()),
+            Expr::Binary { ref lhs, ref rhs, .. } => lhs.span().to(&rhs.span()),
+            Expr::Chain { ref operands, .. } => match (operands.first(), operands.last()) {
+                (Some(first), Some(last)) => first.span().to(&last.span()),
+                _ => Span::default()
+            },
+            Expr::Assignment { ref name, ref value } => name.span.to(&value.span()),
+            Expr::Call { span, .. } => span,
+            Expr::Definition { ref name, ref body, .. } => name.span.to(&body.span()),
+            Expr::Sequence(ref statements) => match (statements.first(), statements.last()) {
+                (Some(first), Some(last)) => first.span().to(&last.span()),
+                _ => Span::default()
+            },
+            Expr::Interval { span, .. } => span,
+            Expr::Conditional { ref condition, ref otherwise, .. } => condition.span().to(&otherwise.span())
+        }
+    }
+
+    // Postfix (reverse polish notation) form of the tree, as `parse` used to
+    // return it. A chain of comparisons is each comparison, joined by `&&`,
+    // an interval is its two bounds followed by the closing bracket, spanning
+    // the whole interval, a conditional its three operands followed by `:`,
+    // spanning the whole conditional, an assignment the name and the value
+    // followed by `=`, a call the arguments followed by the function name, a
+    // definition the parameters and the body followed by the name and `=`,
+    // and statements are separated by `;`.
+    pub fn to_rpn(&self) -> Vec<Spanned<Token>> {
+        // Subtrees left to convert, and tokens waiting for them, in reverse
+        // order: deep trees are converted without recursion.
+        enum Step<'a> {
+            Convert(&'a Expr),
+            Emit(Spanned<Token>),
+            // A `;`, spanning the last token before it.
+            Separate
+        }
+
+        let mut tokens: Vec<Spanned<Token>> = Vec::new();
+        let mut steps: Vec<Step> = vec![Step::Convert(self)];
+
+        while let Some(step) = steps.pop() {
+            let expr: &Expr = match step {
+                Step::Convert(expr) => expr,
+                Step::Emit(token) => {
+                    tokens.push(token);
+                    continue;
+                },
+                Step::Separate => {
+                    let span: Span = tokens.last().map_or(Span::default(), |token| token.span);
+                    tokens.push(Spanned::new(Token::Semicolon, span));
+                    continue;
+                }
+            };
+
+            match *expr {
+                Expr::Number(ref literal) => {
+                    tokens.push(Spanned::new(Token::Literal(literal.node.clone()), literal.span));
+                },
+                Expr::Variable(ref name) => {
+                    tokens.push(Spanned::new(Token::Identifier(name.node.clone()), name.span));
+                },
+                Expr::Unary { ref op, ref operand } => {
+                    steps.push(Step::Emit(Spanned::new(Token::UnaryOperator(op.node.clone()), op.span)));
+                    steps.push(Step::Convert(operand));
+                },
+                Expr::Binary { ref op, ref lhs, ref rhs } => {
+                    steps.push(Step::Emit(Spanned::new(Token::Operator(op.node.clone()), op.span)));
+                    steps.push(Step::Convert(rhs));
+                    steps.push(Step::Convert(lhs));
+                },
+                Expr::Chain { ref operands, ref ops } => {
+                    for (index, (op, pair)) in ops.iter().zip(operands.windows(2)).enumerate().rev() {
+                        if index > 0 {
+                            steps.push(Step::Emit(Spanned::new(Token::Operator(Operator::And), op.span)));
+                        }
+                        steps.push(Step::Emit(Spanned::new(Token::Operator(op.node.clone()), op.span)));
+                        steps.push(Step::Convert(&pair[1]));
+                        steps.push(Step::Convert(&pair[0]));
+                    }
+                },
+                Expr::Assignment { ref name, ref value } => {
+                    tokens.push(Spanned::new(Token::Identifier(name.node.clone()), name.span));
+                    steps.push(Step::Emit(Spanned::new(Token::Equals, expr.span())));
+                    steps.push(Step::Convert(value));
+                },
+                Expr::Call { ref name, ref arguments, .. } => {
+                    steps.push(Step::Emit(Spanned::new(Token::Identifier(name.node.clone()), name.span)));
+                    steps.extend(arguments.iter().rev().map(Step::Convert));
+                },
+                Expr::Definition { ref name, ref parameters, ref body } => {
+                    for parameter in parameters {
+                        tokens.push(Spanned::new(Token::Identifier(parameter.node.clone()), parameter.span));
+                    }
+                    steps.push(Step::Emit(Spanned::new(Token::Equals, expr.span())));
+                    steps.push(Step::Emit(Spanned::new(Token::Identifier(name.node.clone()), name.span)));
+                    steps.push(Step::Convert(body));
+                },
+                Expr::Sequence(ref statements) => {
+                    for (index, statement) in statements.iter().enumerate().rev() {
+                        steps.push(Step::Convert(statement));
+                        if index > 0 { steps.push(Step::Separate); }
+                    }
+                },
+                Expr::Interval { ref lower, ref upper, span } => {
+                    steps.push(Step::Emit(Spanned::new(Token::RightBracket, span)));
+                    steps.push(Step::Convert(upper));
+                    steps.push(Step::Convert(lower));
+                },
+                Expr::Conditional { ref condition, ref then, ref otherwise } => {
+                    steps.push(Step::Emit(Spanned::new(Token::Colon, expr.span())));
+                    steps.push(Step::Convert(otherwise));
+                    steps.push(Step::Convert(then));
+                    steps.push(Step::Convert(condition));
+                }
+            }
+        }
+
+        tokens
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        fn fmt_operand(operand: &Expr, f: &mut Formatter) -> Result<(), Error> {
+            match *operand {
+                Expr::Number(_) | Expr::Variable(_) | Expr::Call { .. } | Expr::Interval { .. } => {
+                    operand.fmt(f)
+                },
+                _ => {
+                    f.write_str("(")?;
+                    operand.fmt(f)?;
+                    f.write_str(")")
+                }
+            }
+        }
+
+        match *self {
+            Expr::Number(ref literal) => write!(f, "{}", literal.node),
+            Expr::Variable(ref name) => write!(f, "{}", name.node),
+            Expr::Unary { ref op, ref operand } if op.node.is_postfix() => {
+                fmt_operand(operand, f)?;
+                write!(f, "{}", op.node)
+            },
+            Expr::Unary { ref op, ref operand } => {
+                write!(f, "{}", op.node)?;
+                fmt_operand(operand, f)
+            },
+            Expr::Binary { ref op, ref lhs, ref rhs } => {
+                fmt_operand(lhs, f)?;
+                write!(f, " {} ", op.node)?;
+                fmt_operand(rhs, f)
+            },
+            Expr::Chain { ref operands, ref ops } => {
+                for (index, operand) in operands.iter().enumerate() {
+                    if let Some(op) = index.checked_sub(1).and_then(|index| ops.get(index)) {
+                        write!(f, " {} ", op.node)?;
+                    }
+                    fmt_operand(operand, f)?;
+                }
+                Ok(())
+            },
+            Expr::Assignment { ref name, ref value } => write!(f, "{} = {}", name.node, value),
+            Expr::Call { ref name, ref arguments, .. } => {
+                let arguments: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+                write!(f, "{}({})", name.node, arguments.join(", "))
+            },
+            Expr::Definition { ref name, ref parameters, ref body } => {
+                let parameters: Vec<&str> = parameters.iter().map(|parameter| parameter.node.as_str()).collect();
+                write!(f, "{}({}) = {}", name.node, parameters.join(", "), body)
+            },
+            Expr::Sequence(ref statements) => {
+                let statements: Vec<String> = statements.iter().map(|statement| statement.to_string()).collect();
+                write!(f, "{}", statements.join("; "))
+            },
+            Expr::Interval { ref lower, ref upper, .. } => write!(f, "[{}, {}]", lower, upper),
+            Expr::Conditional { ref condition, ref then, ref otherwise } => {
+                fmt_operand(condition, f)?;
+                write!(f, " ? ")?;
+                fmt_operand(then, f)?;
+                write!(f, " : ")?;
+                fmt_operand(otherwise, f)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parser::parse;
+    use tokenizer::tokenize;
+
+    #[test]
+    fn expressions_can_be_converted_back_to_rpn() {
+        let expr: Expr = parse(tokenize("(22+3)*2").unwrap()).unwrap();
+        let result: String = expr.to_rpn()
+            .iter()
+            .map(|token| token.node.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        assert_eq!(result, "22 3 + 2 *");
+    }
+
+    #[test]
+    fn unary_operators_come_after_their_operand_in_rpn() {
+        let expr: Expr = parse(tokenize("-2^2").unwrap()).unwrap();
+        let result: Vec<Token> = expr.to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
+
+        let expected_result: Vec<Token> = [
+            Token::Literal(Literal::from(String::from("2"))),
+            Token::Literal(Literal::from(String::from("2"))),
+            Token::Operator(Operator::Caret),
+            Token::UnaryOperator(UnaryOperator::Minus)
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn expressions_are_displayed_with_explicit_grouping() {
+        let expr: Expr = parse(tokenize("22+3*2^2").unwrap()).unwrap();
+
+        assert_eq!(expr.to_string(), "22 + (3 * (2 ^ 2))");
+    }
+
+    #[test]
+    fn expressions_span_all_of_their_operands() {
+        let expr: Expr = parse(tokenize(" (22+3)*2").unwrap()).unwrap();
+
+        assert_eq!(expr.span(), Span::new(2, 7, 1, 3));
+    }
+
+    #[test]
+    fn intervals_span_both_brackets() {
+        let expr: Expr = parse(tokenize("2*[1.9, -2+4]").unwrap()).unwrap();
+
+        assert_eq!(expr.to_string(), "2 * [1.9, (-2) + 4]");
+        assert_eq!(expr.span(), Span::new(0, 13, 1, 1));
+        assert_eq!(
+            expr.to_rpn().iter().map(|token| token.node.to_string()).collect::<Vec<String>>().join(" "),
+            "2 1.9 2 - 4 + ] *"
+        );
+    }
+
+    #[test]
+    fn statements_are_separated_by_semicolons() {
+        let expr: Expr = parse(tokenize("r = 3; area = 3 * r^2; area").unwrap()).unwrap();
+
+        assert_eq!(expr.to_string(), "r = 3; area = 3 * (r ^ 2); area");
+        assert_eq!(
+            expr.to_rpn().iter().map(|token| token.node.to_string()).collect::<Vec<String>>().join(" "),
+            "r 3 = ; area 3 r 2 ^ * = ; area"
+        );
+        assert_eq!(expr.span(), Span::new(0, 27, 1, 1));
+    }
+
+    #[test]
+    fn functions_are_displayed_with_their_arguments() {
+        let expr: Expr = parse(tokenize("f(x, y) = x^2 + y; 2*f(3, g(4))").unwrap()).unwrap();
+
+        assert_eq!(expr.to_string(), "f(x, y) = (x ^ 2) + y; 2 * f(3, g(4))");
+        assert_eq!(
+            expr.to_rpn().iter().map(|token| token.node.to_string()).collect::<Vec<String>>().join(" "),
+            "x y x 2 ^ y + f = ; 2 3 4 g f *"
+        );
+    }
+
+    #[test]
+    fn chained_comparisons_hold_each_operand_once() {
+        let expr: Expr = parse(tokenize("0 <= f(x) < 10").unwrap()).unwrap();
+
+        assert_eq!(expr.to_string(), "0 <= f(x) < 10");
+        assert_eq!(expr.span(), Span::new(0, 14, 1, 1));
+        assert_eq!(
+            expr.to_rpn().iter().map(|token| token.node.to_string()).collect::<Vec<String>>().join(" "),
+            "0 x f <= x f 10 < &&"
+        );
+    }
+}
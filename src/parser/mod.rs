@@ -1,55 +1,484 @@
+pub mod expr;
+use self::expr::Expr;
+use error::EvalError;
+use tokenizer::span::{Span, Spanned};
 use tokenizer::token::*;
+use tokenizer::token::operator::Operator;
+use tokenizer::token::unary_operator::UnaryOperator;
+
+// Deepest tree the parser builds, each operand of a chain such as `1 + 2 + 3`
+// adding a level. The interpreter walks trees with a stack of its own, but
+// dropping, cloning, printing or taking the span of a tree recurses, which has
+// to fit on the 2 MB stack of a spawned thread.
+const MAX_DEPTH: usize = 1_000;
+
+// Operands of the expression being parsed, each with the depth of its tree,
+// which fails to grow past `MAX_DEPTH`.
+#[derive(Default)]
+struct Operands(Vec<(Expr, usize)>);
+
+impl Operands {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&Expr> {
+        self.0.get(index).map(|(expr, _)| expr)
+    }
+
+    fn last(&self) -> Option<&Expr> {
+        self.0.last().map(|(expr, _)| expr)
+    }
+
+    // Topmost operand and its depth, for an operator at `span`.
+    fn pop(&mut self, span: Span) -> Result<(Expr, usize), EvalError> {
+        self.0.pop().ok_or(EvalError::MissingOperand(span))
+    }
+
+    fn push(&mut self, expr: Expr, depth: usize) -> Result<(), EvalError> {
+        if depth > MAX_DEPTH { return Err(EvalError::TooDeep(expr.span())); }
+
+        self.0.push((expr, depth));
+        Ok(())
+    }
+
+    // Operands from `index` on, with the depth of the deepest one.
+    fn split_off(&mut self, index: usize) -> (Vec<Expr>, usize) {
+        let operands: Vec<(Expr, usize)> = self.0.split_off(index);
+        let depth: usize = operands.iter().map(|&(_, depth)| depth).max().unwrap_or(0);
+
+        (operands.into_iter().map(|(expr, _)| expr).collect(), depth)
+    }
+}
+
+fn is_group_start(token: &Spanned<Token>) -> bool {
+    token.node == Token::LeftParenthesis || token.node == Token::LeftBracket
+}
+
+// Replaces the two topmost operands by their combination through `operator`,
+// or gives the topmost one to the chain of comparisons left open for
+// `operator` below it.
+fn apply_operator(operator: Spanned<Operator>, operands: &mut Operands) -> Result<(), EvalError> {
+    let (rhs, rhs_depth): (Expr, usize) = operands.pop(operator.span)?;
+    let (lhs, lhs_depth): (Expr, usize) = operands.pop(operator.span)?;
+
+    match lhs {
+        Expr::Chain { operands: mut chain, ops } if ops.len() == chain.len() && ops.last() == Some(&operator) => {
+            chain.push(rhs);
+            operands.push(Expr::Chain { operands: chain, ops }, lhs_depth.max(rhs_depth + 1))
+        },
+        lhs => operands.push(
+            Expr::Binary { op: operator, lhs: Box::new(lhs), rhs: Box::new(rhs) },
+            lhs_depth.max(rhs_depth) + 1
+        )
+    }
+}
+
+// Chain continuing `comparison` with `operator`, which is left open until
+// the operator is applied to its right operand.
+fn open_chain(comparison: Expr, operator: Spanned<Operator>) -> Expr {
+    match comparison {
+        Expr::Binary { op, lhs, rhs } => Expr::Chain { operands: vec![*lhs, *rhs], ops: vec![op, operator] },
+        Expr::Chain { operands, mut ops } => {
+            ops.push(operator);
+            Expr::Chain { operands, ops }
+        },
+        expr => expr
+    }
+}
+
+// Replaces the topmost operand by its combination through `operator`.
+fn apply_unary_operator(operator: Spanned<UnaryOperator>, operands: &mut Operands) -> Result<(), EvalError> {
+    let (operand, depth): (Expr, usize) = operands.pop(operator.span)?;
+
+    operands.push(Expr::Unary { op: operator, operand: Box::new(operand) }, depth + 1)
+}
 
-pub fn parse(tokens: Vec<Token>) -> Vec<Token> {
-    let mut ast: Vec<Token> = Vec::new();
-    let mut operator_stack: Vec<Token> = Vec::new();
+// Replaces the three topmost operands by the conditional they form, the `:`
+// separating the last two being `separator`.
+fn apply_conditional(separator: Span, operands: &mut Operands) -> Result<(), EvalError> {
+    let (otherwise, otherwise_depth): (Expr, usize) = operands.pop(separator)?;
+    let (then, then_depth): (Expr, usize) = operands.pop(separator)?;
+    let (condition, condition_depth): (Expr, usize) = operands.pop(separator)?;
+
+    operands.push(
+        Expr::Conditional {
+            condition: Box::new(condition),
+            then: Box::new(then),
+            otherwise: Box::new(otherwise)
+        },
+        condition_depth.max(then_depth).max(otherwise_depth) + 1
+    )
+}
+
+fn apply_token(token: Spanned<Token>, operands: &mut Operands) -> Result<(), EvalError> {
+    match token.node {
+        Token::Colon => apply_conditional(token.span, operands),
+        Token::Operator(operator) => apply_operator(Spanned::new(operator, token.span), operands),
+        Token::UnaryOperator(operator) => apply_unary_operator(Spanned::new(operator, token.span), operands),
+        node => Err(EvalError::UnexpectedToken(Spanned::new(node, token.span)))
+    }
+}
+
+// Applies the operators stacked since the innermost opening parenthesis or
+// bracket, which is left on the stack.
+fn apply_group(operator_stack: &mut Vec<Spanned<Token>>, operands: &mut Operands) -> Result<(), EvalError> {
+    while let Some(top_token) = operator_stack.pop() {
+        if is_group_start(&top_token) {
+            operator_stack.push(top_token);
+            break;
+        }
+
+        apply_token(top_token, operands)?;
+    }
+    Ok(())
+}
+
+// Whether the group started at `index` of the stack holds the arguments of
+// a function call, the function name being stacked right before it.
+fn is_call(operator_stack: &[Spanned<Token>], index: usize) -> bool {
+    operator_stack[index].node == Token::LeftParenthesis
+        && index > 0
+        && matches!(operator_stack[index - 1].node, Token::Identifier(_))
+}
+
+// Closes the innermost group, which must have been opened by `opening`.
+fn close_group(
+    closing: &Spanned<Token>,
+    opening: &Token,
+    expect_operand: bool,
+    operator_stack: &mut Vec<Spanned<Token>>,
+    operands: &mut Operands
+) -> Result<Spanned<Token>, EvalError> {
+    match operator_stack.iter().rev().find(|token| is_group_start(token)) {
+        Some(token) if token.node == *opening => {},
+        _ => return Err(EvalError::MismatchedParenthesis(closing.span))
+    }
+    if expect_operand { return Err(EvalError::MissingOperand(closing.span)); }
+
+    apply_group(operator_stack, operands)?;
+    operator_stack.pop().ok_or(EvalError::MismatchedParenthesis(closing.span))
+}
+
+// Splits the tokens at each `;`, and at each newline ending a complete
+// statement, i.e. outside of parentheses and brackets and not right after an
+// operator. Separators and empty statements are dropped.
+fn split_statements(tokens: Vec<Spanned<Token>>) -> Vec<Vec<Spanned<Token>>> {
+    let mut statements: Vec<Vec<Spanned<Token>>> = Vec::new();
+    let mut statement: Vec<Spanned<Token>> = Vec::new();
+    let mut depth: usize = 0;
 
     for token in tokens {
-        match token {
-            Token::Literal(_) => ast.push(token),
+        match token.node {
+            Token::LeftParenthesis | Token::LeftBracket => depth += 1,
+            Token::RightParenthesis | Token::RightBracket => depth = depth.saturating_sub(1),
+            Token::Semicolon => {
+                statements.push(statement.split_off(0));
+                depth = 0;
+                continue;
+            },
+            Token::Newline => {
+                if depth == 0 && !Token::is_prefix_position(statement.last().map(|token| &token.node)) {
+                    statements.push(statement.split_off(0));
+                }
+                continue;
+            },
+            _ => {}
+        }
+
+        statement.push(token);
+    }
+    statements.push(statement);
+
+    statements.into_iter().filter(|statement| !statement.is_empty()).collect()
+}
+
+// An expression, possibly preceded by `name =`.
+fn parse_assignment(tokens: Vec<Spanned<Token>>) -> Result<Expr, EvalError> {
+    if let [Spanned { node: Token::Identifier(ref name), span }, Spanned { node: Token::Equals, span: equals_span }, ..] = tokens[..] {
+        if tokens.len() == 2 { return Err(EvalError::MissingOperand(equals_span)); }
+
+        let name: Spanned<String> = Spanned::new(name.clone(), span);
+        let value: Expr = parse_assignment(tokens[2..].to_vec())?;
+        return Ok(Expr::Assignment { name, value: Box::new(value) });
+    }
+
+    parse_expression(tokens)
+}
+
+// Distinct identifiers separated by commas.
+fn parse_parameters(tokens: &[Spanned<Token>]) -> Result<Vec<Spanned<String>>, EvalError> {
+    let mut parameters: Vec<Spanned<String>> = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        match token.node {
+            Token::Identifier(ref name) if index % 2 == 0 && parameters.iter().all(|parameter| parameter.node != *name) => {
+                parameters.push(Spanned::new(name.clone(), token.span));
+            },
+            Token::Comma if index % 2 == 1 && index + 1 < tokens.len() => {},
+            _ => return Err(EvalError::UnexpectedToken(token.clone()))
+        }
+    }
+
+    Ok(parameters)
+}
+
+// A function definition `name(parameters) = body` or an assignment.
+fn parse_statement(tokens: Vec<Spanned<Token>>) -> Result<Expr, EvalError> {
+    if let [Spanned { node: Token::Identifier(ref name), span }, Spanned { node: Token::LeftParenthesis, .. }, ..] = tokens[..] {
+        let closing: Option<usize> = tokens.iter().position(|token| token.node == Token::RightParenthesis);
+
+        if let Some(closing) = closing.filter(|&closing| tokens.get(closing + 1).is_some_and(|token| token.node == Token::Equals)) {
+            if tokens.len() == closing + 2 { return Err(EvalError::MissingOperand(tokens[closing + 1].span)); }
+
+            return Ok(Expr::Definition {
+                name: Spanned::new(name.clone(), span),
+                parameters: parse_parameters(&tokens[2..closing])?,
+                body: Box::new(parse_expression(tokens[closing + 2..].to_vec())?)
+            });
+        }
+    }
+
+    parse_assignment(tokens)
+}
+
+// Parses a program of statements separated by `;` or newlines, giving the
+// statement itself when there is only one.
+pub fn parse(tokens: Vec<Spanned<Token>>) -> Result<Expr, EvalError> {
+    let mut statements: Vec<Expr> = split_statements(tokens)
+        .into_iter()
+        .map(parse_statement)
+        .collect::<Result<Vec<Expr>, EvalError>>()?;
+
+    match statements.len() {
+        0 => parse_expression(Vec::new()),
+        1 => Ok(statements.remove(0)),
+        _ => Ok(Expr::Sequence(statements))
+    }
+}
+
+fn parse_expression(tokens: Vec<Spanned<Token>>) -> Result<Expr, EvalError> {
+    let mut operands: Operands = Operands::default();
+    // Holds the names of the functions being called as well, right before
+    // the parenthesis opening their arguments.
+    let mut operator_stack: Vec<Spanned<Token>> = Vec::new();
+    // Number of operands before each opened parenthesis or bracket, to count
+    // the bounds of an interval or the arguments of a call.
+    let mut group_starts: Vec<usize> = Vec::new();
+    // Alternates between operands and operators, which is how misplaced
+    // tokens are detected.
+    let mut expect_operand: bool = true;
+    let mut last_span: Span = Span::default();
+
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        last_span = token.span;
+
+        match token.node {
+            Token::Literal(literal) => {
+                if !expect_operand {
+                    return Err(EvalError::UnexpectedToken(
+                        Spanned::new(Token::Literal(literal), token.span)
+                    ));
+                }
+
+                operands.push(Expr::Number(Spanned::new(literal, token.span)), 1)?;
+                expect_operand = false;
+            },
+            Token::Identifier(name) => {
+                if !expect_operand {
+                    return Err(EvalError::UnexpectedToken(
+                        Spanned::new(Token::Identifier(name), token.span)
+                    ));
+                }
+
+                if tokens.peek().is_some_and(|next| next.node == Token::LeftParenthesis) {
+                    operator_stack.push(Spanned::new(Token::Identifier(name), token.span));
+                } else {
+                    operands.push(Expr::Variable(Spanned::new(name, token.span)), 1)?;
+                    expect_operand = false;
+                }
+            },
             Token::Operator(operator) => {
-                while !operator_stack.is_empty() {
-                    let top_token: Token = operator_stack.last().unwrap().clone();
-
-                    match top_token {
-                        Token::LeftParenthesis => break,
-                        Token::Operator(top_operator) => {
-                            if top_operator > operator {
-                                operator_stack.pop();
-                                ast.push(Token::Operator(top_operator));
-                            } else { break; }
-                        }
-                        _ => panic!("Dev error: The operator_stack should only hold LeftParentheses and Operators."),
+                if expect_operand {
+                    return Err(EvalError::UnexpectedToken(
+                        Spanned::new(Token::Operator(operator), token.span)
+                    ));
+                }
+
+                while let Some(top_token) = operator_stack.last().cloned() {
+                    let takes_precedence: bool = match top_token.node {
+                        Token::Operator(ref top_operator) => top_operator.takes_precedence_over(&operator),
+                        Token::UnaryOperator(ref top_operator) => top_operator.takes_precedence_over(&operator),
+                        _ => false
+                    };
+                    if !takes_precedence { break; }
+
+                    let chained: bool = matches!(top_token.node, Token::Operator(ref top_operator) if top_operator.chains_with(&operator));
+                    operator_stack.pop();
+                    apply_token(top_token, &mut operands)?;
+
+                    // `a < b < c` is `a < b && b < c`, `b` being evaluated
+                    // once: the comparison just built becomes a chain which
+                    // the next one extends.
+                    if chained {
+                        let (comparison, depth): (Expr, usize) = operands.pop(token.span)?;
+                        operands.push(open_chain(comparison, Spanned::new(operator.clone(), token.span)), depth)?;
+                        break;
                     }
                 }
 
-                operator_stack.push(Token::Operator(operator));
+                operator_stack.push(Spanned::new(Token::Operator(operator), token.span));
+                expect_operand = true;
+            },
+            Token::UnaryOperator(ref operator) if operator.is_postfix() => {
+                if expect_operand { return Err(EvalError::UnexpectedToken(token)); }
+
+                // Binding tighter than anything, a postfix operator applies
+                // to the operand just read.
+                apply_token(token, &mut operands)?;
+            },
+            Token::UnaryOperator(_) => {
+                if !expect_operand { return Err(EvalError::UnexpectedToken(token)); }
+
+                // Nothing on the stack can be applied yet: a prefix operator
+                // has no left operand.
+                operator_stack.push(token);
+            },
+            Token::LeftParenthesis => {
+                if !expect_operand { return Err(EvalError::UnexpectedToken(token)); }
+
+                group_starts.push(operands.len());
+                operator_stack.push(token);
             },
-            Token::LeftParenthesis => operator_stack.push(token),
             Token::RightParenthesis => {
-                while !operator_stack.is_empty() {
-                    let top_token: Token = operator_stack.last().unwrap().clone();
+                let top: usize = operator_stack.len().saturating_sub(1);
+                // `f()` has nothing to close but the call.
+                let no_arguments: bool = expect_operand
+                    && !operator_stack.is_empty()
+                    && is_call(&operator_stack, top)
+                    && group_starts.last() == Some(&operands.len());
+                if no_arguments {
+                    operator_stack.pop();
+                } else {
+                    close_group(&token, &Token::LeftParenthesis, expect_operand, &mut operator_stack, &mut operands)?;
+                }
+                let group_start: usize = group_starts.pop().unwrap_or(0);
 
-                    match top_token {
-                        Token::LeftParenthesis => {
-                            operator_stack.pop();
-                            break;
+                if let Some(Token::Identifier(name)) = operator_stack.last().map(|top_token| top_token.node.clone()) {
+                    let name_span: Span = operator_stack.pop().map_or(token.span, |top_token| top_token.span);
+                    let (arguments, depth): (Vec<Expr>, usize) = operands.split_off(group_start);
+
+                    operands.push(
+                        Expr::Call {
+                            name: Spanned::new(name, name_span),
+                            arguments,
+                            span: name_span.to(&token.span)
                         },
-                        Token::Operator(top_operator) => {
-                            operator_stack.pop();
-                            ast.push(Token::Operator(top_operator));
-                        }
-                        _ => panic!("Dev error: The operator_stack should only hold LeftParentheses and Operators."),
+                        depth + 1
+                    )?;
+                }
+                expect_operand = false;
+            },
+            Token::LeftBracket => {
+                if !expect_operand { return Err(EvalError::UnexpectedToken(token)); }
+
+                group_starts.push(operands.len());
+                operator_stack.push(token);
+            },
+            Token::Comma => {
+                let separates_values: bool = operator_stack
+                    .iter()
+                    .rposition(is_group_start)
+                    .is_some_and(|index| {
+                        operator_stack[index].node == Token::LeftBracket || is_call(&operator_stack, index)
+                    });
+                if !separates_values { return Err(EvalError::UnexpectedToken(token)); }
+                if expect_operand { return Err(EvalError::MissingOperand(token.span)); }
+
+                apply_group(&mut operator_stack, &mut operands)?;
+                expect_operand = true;
+            },
+            Token::RightBracket => {
+                let opening: Spanned<Token> = close_group(
+                    &token, &Token::LeftBracket, expect_operand, &mut operator_stack, &mut operands
+                )?;
+                let bounds: usize = operands.len() - group_starts.pop().unwrap_or(0);
+
+                if bounds < 2 { return Err(EvalError::MissingOperand(token.span)); }
+                if bounds > 2 {
+                    let trailing_operand: Option<&Expr> = operands.get(operands.len() - bounds + 2);
+                    return Err(EvalError::TrailingOperand(trailing_operand.map_or(token.span, Expr::span)));
+                }
+
+                let (upper, upper_depth): (Expr, usize) = operands.pop(token.span)?;
+                let (lower, lower_depth): (Expr, usize) = operands.pop(token.span)?;
+                operands.push(
+                    Expr::Interval {
+                        lower: Box::new(lower),
+                        upper: Box::new(upper),
+                        span: opening.span.to(&token.span)
+                    },
+                    lower_depth.max(upper_depth) + 1
+                )?;
+            },
+            Token::QuestionMark => {
+                if expect_operand { return Err(EvalError::UnexpectedToken(token)); }
+
+                // Every operator binds tighter than a conditional. Stacked
+                // conditionals are left for `a ? b : c ? d : e` to be
+                // `a ? b : (c ? d : e)`.
+                while let Some(top_token) = operator_stack.last().cloned() {
+                    if !matches!(top_token.node, Token::Operator(_) | Token::UnaryOperator(_)) { break; }
+
+                    operator_stack.pop();
+                    apply_token(top_token, &mut operands)?;
+                }
+
+                operator_stack.push(token);
+                expect_operand = true;
+            },
+            Token::Colon => {
+                if expect_operand { return Err(EvalError::MissingOperand(token.span)); }
+
+                // Completes the value given when the condition holds,
+                // including the conditionals nested in it, up to its `?`
+                // which the `:` replaces on the stack.
+                loop {
+                    match operator_stack.last().map(|top_token| &top_token.node) {
+                        Some(Token::Operator(_)) | Some(Token::UnaryOperator(_)) | Some(Token::Colon) => {
+                            let top_token: Spanned<Token> = operator_stack.pop().unwrap();
+                            apply_token(top_token, &mut operands)?;
+                        },
+                        Some(Token::QuestionMark) => break,
+                        _ => return Err(EvalError::UnexpectedToken(token))
                     }
                 }
+
+                operator_stack.pop();
+                operator_stack.push(token);
+                expect_operand = true;
             },
+            Token::Equals | Token::Semicolon | Token::Newline => return Err(EvalError::UnexpectedToken(token))
         }
     }
 
-    operator_stack.reverse();
-    ast.append(&mut operator_stack);
+    if let Some(group_start) = operator_stack.iter().find(|token| is_group_start(token)) {
+        return Err(EvalError::MismatchedParenthesis(group_start.span));
+    }
+    if expect_operand { return Err(EvalError::MissingOperand(last_span)); }
+
+    while let Some(top_token) = operator_stack.pop() {
+        apply_token(top_token, &mut operands)?;
+    }
 
-    ast
+    let (ast, _): (Expr, usize) = operands.pop(last_span)?;
+    if let Some(trailing_operand) = operands.last() {
+        return Err(EvalError::TrailingOperand(trailing_operand.span()));
+    }
+
+    Ok(ast)
 }
 
 #[cfg(test)]
@@ -57,23 +486,23 @@ mod tests {
     use super::*;
     use tokenizer::tokenize;
     use tokenizer::token::literal::Literal;
-    use tokenizer::token::operator::Operator;
 
     #[test]
     fn parse_with_empty_list_of_tokens() {
-        let tokens: Vec<Token> = tokenize("");
-        let result: Vec<Token> = parse(tokens);
-
-        let expected_result: Vec<Token> = [
-        ].to_vec();
+        let tokens: Vec<Spanned<Token>> = tokenize("").unwrap();
+        let result: Result<Expr, EvalError> = parse(tokens);
 
-       assert_eq!(result, expected_result);
+       assert_eq!(result, Err(EvalError::MissingOperand(Span::default())));
     }
 
     #[test]
     fn parse_with_a_two_literals_multiplication() {
-        let tokens: Vec<Token> = tokenize("22*3");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("22*3").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
@@ -86,8 +515,12 @@ mod tests {
 
     #[test]
     fn parse_with_multiplication_and_addition() {
-        let tokens: Vec<Token> = tokenize("22*3+2");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("22*3+2").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
@@ -102,8 +535,12 @@ mod tests {
 
     #[test]
     fn parse_with_addition_and_multiplication() {
-        let tokens: Vec<Token> = tokenize("22+3*2");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("22+3*2").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
@@ -118,8 +555,12 @@ mod tests {
 
     #[test]
     fn parse_with_multiplication_and_addition_and_multiplication() {
-        let tokens: Vec<Token> = tokenize("22*3+2*3");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("22*3+2*3").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
@@ -136,16 +577,20 @@ mod tests {
 
     #[test]
     fn parse_with_addition_and_multiplication_and_addition() {
-        let tokens: Vec<Token> = tokenize("22+3*2+3");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("22+3*2+3").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
             Token::Literal(Literal::from(String::from("3"))),
             Token::Literal(Literal::from(String::from("2"))),
             Token::Operator(Operator::Times),
-            Token::Literal(Literal::from(String::from("3"))),
             Token::Operator(Operator::Plus),
+            Token::Literal(Literal::from(String::from("3"))),
             Token::Operator(Operator::Plus)
         ].to_vec();
 
@@ -154,16 +599,20 @@ mod tests {
 
     #[test]
     fn parse_with_multiplication_and_addition_and_addition() {
-        let tokens: Vec<Token> = tokenize("22*3+2+3");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("22*3+2+3").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
             Token::Literal(Literal::from(String::from("3"))),
             Token::Operator(Operator::Times),
             Token::Literal(Literal::from(String::from("2"))),
-            Token::Literal(Literal::from(String::from("3"))),
             Token::Operator(Operator::Plus),
+            Token::Literal(Literal::from(String::from("3"))),
             Token::Operator(Operator::Plus)
         ].to_vec();
 
@@ -172,16 +621,20 @@ mod tests {
 
     #[test]
     fn parse_with_addition_and_addition_and_multiplication() {
-        let tokens: Vec<Token> = tokenize("22+3+2*3");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("22+3+2*3").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
             Token::Literal(Literal::from(String::from("3"))),
+            Token::Operator(Operator::Plus),
             Token::Literal(Literal::from(String::from("2"))),
             Token::Literal(Literal::from(String::from("3"))),
             Token::Operator(Operator::Times),
-            Token::Operator(Operator::Plus),
             Token::Operator(Operator::Plus)
         ].to_vec();
 
@@ -190,8 +643,12 @@ mod tests {
 
     #[test]
     fn parse_with_parentheses() {
-        let tokens: Vec<Token> = tokenize("(22+3)*2");
-        let result: Vec<Token> = parse(tokens);
+        let tokens: Vec<Spanned<Token>> = tokenize("(22+3)*2").unwrap();
+        let result: Vec<Token> = parse(tokens).unwrap()
+            .to_rpn()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
@@ -203,4 +660,337 @@ mod tests {
 
        assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn parse_groups_operators_following_their_associativity() {
+        let result: Vec<String> = ["3-2-1", "8/4/2", "2^3^2"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["(3 - 2) - 1", "(8 / 4) / 2", "2 ^ (3 ^ 2)"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_unary_operators() {
+        let result: Vec<String> = ["-3", "2*-3", "-(1+2)", "-2^2", "2^-1", "--2"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["-3", "2 * (-3)", "-(1 + 2)", "-(2 ^ 2)", "2 ^ (-1)", "-(-2)"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_modulo_integer_division_and_factorial() {
+        let result: Vec<String> = ["7 % 3 * 2", "1 + 7 // 2", "-3!", "2^3!", "(1+2)!!", "f(3)! - 1", "3!\n4"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["(7 % 3) * 2", "1 + (7 // 2)", "-(3!)", "2 ^ (3!)", "((1 + 2)!)!", "(f(3)!) - 1", "3!; 4"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_comparisons_and_logical_operators() {
+        let result: Vec<String> = ["1 + 2 < 4 == x >= 1", "!a && b || c", "a || b && c", "!(x < 1)"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["((1 + 2) < 4) == (x >= 1)", "((!a) && b) || c", "a || (b && c)", "!(x < 1)"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_bitwise_operators() {
+        let result: Vec<String> = ["a | b xor c & d", "a & b == c", "1 << n - 1 < m", "~a & -b", "a && b | c"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "a | (b xor (c & d))",
+            "a & (b == c)",
+            "(1 << (n - 1)) < m",
+            "(~a) & (-b)",
+            "a && (b | c)"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_chains_comparisons_of_the_same_precedence() {
+        let result: Vec<String> = ["0 <= x < 10", "a < b > c <= d", "a == b ~= c", "(a < b) < c", "a < b == c", "a < b < c && d"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "0 <= x < 10",
+            "a < b > c <= d",
+            "a == b ≈ c",
+            "(a < b) < c",
+            "(a < b) == c",
+            "(a < b < c) && d"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_conditionals() {
+        let result: Vec<String> = ["a < 1 ? 2 : 3 + 4", "a ? b : c ? d : e", "a ? b ? c : d : e", "f(a ? 1 : 2, [a ? 1 : 2, 3])"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "(a < 1) ? 2 : (3 + 4)",
+            "a ? b : (c ? d : e)",
+            "a ? (b ? c : d) : e",
+            "f(a ? 1 : 2, [a ? 1 : 2, 3])"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_conditionals() {
+        let result: Vec<Result<Expr, EvalError>> = ["a ? b", "a : b", "(a ? b) : c", "a ? : b", "? a : b"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Expr, EvalError>> = [
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::QuestionMark, Span::new(2, 1, 1, 3)))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::Colon, Span::new(2, 1, 1, 3)))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::QuestionMark, Span::new(3, 1, 1, 4)))),
+            Err(EvalError::MissingOperand(Span::new(4, 1, 1, 5))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::QuestionMark, Span::new(0, 1, 1, 1))))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_rejects_expressions_nested_too_deeply() {
+        let sum: String = vec!["1"; MAX_DEPTH].join("+");
+        assert!(parse(tokenize(&sum).unwrap()).is_ok());
+
+        let parentheses: String = format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000));
+        assert!(parse(tokenize(&parentheses).unwrap()).is_ok());
+
+        let result: Vec<Result<Expr, EvalError>> = [
+            vec!["1"; MAX_DEPTH + 1].join("+"),
+            format!("{}1", "-".repeat(1_000_000)),
+            format!("{}x{}", "f(".repeat(2 * MAX_DEPTH), ")".repeat(2 * MAX_DEPTH))
+        ]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Expr, EvalError>> = [
+            Err(EvalError::TooDeep(Span::new(0, 2 * MAX_DEPTH + 1, 1, 1))),
+            Err(EvalError::TooDeep(Span::new(1_000_000 - MAX_DEPTH, MAX_DEPTH + 1, 1, 1_000_001 - MAX_DEPTH))),
+            Err(EvalError::TooDeep(Span::new(2 * MAX_DEPTH, 3 * MAX_DEPTH + 1, 1, 2 * MAX_DEPTH + 1)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_rejects_a_factorial_without_operand() {
+        // A prefix `!` is a negation for the tokenizer, so such tokens only
+        // come from elsewhere.
+        let tokens: Vec<Spanned<Token>> = [
+            Spanned::new(Token::UnaryOperator(UnaryOperator::Factorial), Span::new(0, 1, 1, 1)),
+            Spanned::new(Token::Literal(Literal::from(String::from("3"))), Span::new(1, 1, 1, 2))
+        ].to_vec();
+        let result: Result<Expr, EvalError> = parse(tokens);
+
+        let expected_result: Result<Expr, EvalError> = Err(EvalError::UnexpectedToken(
+            Spanned::new(Token::UnaryOperator(UnaryOperator::Factorial), Span::new(0, 1, 1, 1))
+        ));
+
+        assert_eq!(result, expected_result);
+        assert_eq!(parse(tokenize("2 * !3").unwrap()).unwrap().to_string(), "2 * (!3)");
+    }
+
+    #[test]
+    fn parse_rejects_an_expression_ending_with_an_operator() {
+        let tokens: Vec<Spanned<Token>> = tokenize("3+").unwrap();
+        let result: Result<Expr, EvalError> = parse(tokens);
+
+        assert_eq!(result, Err(EvalError::MissingOperand(Span::new(1, 1, 1, 2))));
+    }
+
+    #[test]
+    fn parse_rejects_unbalanced_parentheses() {
+        let result: Vec<Result<Expr, EvalError>> = [")(", "(2+3", "2+3)"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Expr, EvalError>> = [
+            Err(EvalError::MismatchedParenthesis(Span::new(0, 1, 1, 1))),
+            Err(EvalError::MismatchedParenthesis(Span::new(0, 1, 1, 1))),
+            Err(EvalError::MismatchedParenthesis(Span::new(3, 1, 1, 4)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_rejects_misplaced_tokens() {
+        let result: Vec<Result<Expr, EvalError>> = ["2*(3)(4)", "2**3"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Expr, EvalError>> = [
+            Err(EvalError::UnexpectedToken(
+                Spanned::new(Token::LeftParenthesis, Span::new(5, 1, 1, 6))
+            )),
+            Err(EvalError::UnexpectedToken(
+                Spanned::new(Token::Operator(Operator::Times), Span::new(2, 1, 1, 3))
+            ))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_intervals() {
+        let tokens: Vec<Spanned<Token>> = tokenize("[1, (2+3)*2] - [-1, 1]").unwrap();
+        let result: String = parse(tokens).unwrap().to_string();
+
+        assert_eq!(result, "[1, (2 + 3) * 2] - [-1, 1]");
+    }
+
+    #[test]
+    fn parse_rejects_malformed_intervals() {
+        let result: Vec<Result<Expr, EvalError>> = ["[1]", "[1, 2, 3]", "[1, 2)", "(1, 2)", "[1,]", "[1, 2"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Expr, EvalError>> = [
+            Err(EvalError::MissingOperand(Span::new(2, 1, 1, 3))),
+            Err(EvalError::TrailingOperand(Span::new(7, 1, 1, 8))),
+            Err(EvalError::MismatchedParenthesis(Span::new(5, 1, 1, 6))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::Comma, Span::new(2, 1, 1, 3)))),
+            Err(EvalError::MissingOperand(Span::new(3, 1, 1, 4))),
+            Err(EvalError::MismatchedParenthesis(Span::new(0, 1, 1, 1)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_variables() {
+        let tokens: Vec<Spanned<Token>> = tokenize("2*x^n").unwrap();
+        let result: String = parse(tokens).unwrap().to_string();
+
+        assert_eq!(result, "2 * (x ^ n)");
+    }
+
+    #[test]
+    fn parse_with_statements() {
+        let result: Vec<String> = ["x = 3; x", "x = y = -2\n\nx + y;", "x = 1 +\n 2\n(x\n*2)", "x = 3"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["x = 3; x", "x = y = -2; x + y", "x = 1 + 2; x * 2", "x = 3"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_rejects_misplaced_assignments() {
+        let result: Vec<Result<Expr, EvalError>> = ["x =", "2 = 3", "x + y = 3", ";"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Expr, EvalError>> = [
+            Err(EvalError::MissingOperand(Span::new(2, 1, 1, 3))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::Equals, Span::new(2, 1, 1, 3)))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::Equals, Span::new(6, 1, 1, 7)))),
+            Err(EvalError::MissingOperand(Span::default()))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_with_function_calls() {
+        let result: Vec<String> = ["f(1, 2+3)*2", "-f(g(x), [1, 2])^2", "f()", "f(x) = x^2; f(f(2))"]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()).unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = [
+            "f(1, 2 + 3) * 2",
+            "-(f(g(x), [1, 2]) ^ 2)",
+            "f()",
+            "f(x) = x ^ 2; f(f(2))"
+        ]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_functions() {
+        let result: Vec<Result<Expr, EvalError>> = ["f(1,)", "f(x, x) = x", "f(x y) = x", "f(2) = 2", "f(x) ="]
+            .iter()
+            .map(|string| parse(tokenize(string).unwrap()))
+            .collect();
+
+        let expected_result: Vec<Result<Expr, EvalError>> = [
+            Err(EvalError::MissingOperand(Span::new(4, 1, 1, 5))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::Identifier(String::from("x")), Span::new(5, 1, 1, 6)))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::Identifier(String::from("y")), Span::new(4, 1, 1, 5)))),
+            Err(EvalError::UnexpectedToken(Spanned::new(Token::Literal(Literal::from(String::from("2"))), Span::new(2, 1, 1, 3)))),
+            Err(EvalError::MissingOperand(Span::new(5, 1, 1, 6)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
 }
@@ -1,55 +1,211 @@
+pub mod span;
 pub mod token;
+use error::EvalError;
+use self::span::{Span, Spanned};
 use self::token::*;
-use self::token::literal::Literal;
+use self::token::literal::{radix_prefix, Literal};
 use self::token::literal::digit::Digit;
+use self::token::literal::radix::Radix;
+use self::token::operator::Operator;
+use self::token::unary_operator::UnaryOperator;
 
-pub fn tokenize(expression: &str) -> Vec<Token> {
-    fn pop_literal_buffer_to_tokens(buffer: &mut Vec<Digit>, tokens: &mut Vec<Token>) {
-        let token: Token = Token::Literal(
-            Literal::new(buffer.clone())
-        );
-        tokens.push(token);
-        buffer.clear();
+const THIN_SPACE: char = '\u{2009}';
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TokenizerOptions {
+    // Also accept the thin space and the apostrophe as digit separators,
+    // as in `1'000'000`. The underscore is always accepted.
+    pub locale_separators: bool
+}
+
+impl TokenizerOptions {
+    pub fn is_separator(&self, character: char) -> bool {
+        character == '_' || (self.locale_separators && (character == THIN_SPACE || character == '\''))
+    }
+}
+
+fn is_identifier_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+// Identifiers are made of letters, digits and underscores, but cannot start
+// with a digit.
+fn is_identifier_start(character: char) -> bool {
+    character.is_alphabetic() || character == '_'
+}
+
+// A lone `i` is an identifier, which the interpreter reads as the imaginary
+// unit when the number type has one.
+fn is_literal_start(characters: &[(usize, char)]) -> bool {
+    match characters {
+        [(_, character), ..] => Digit::new(*character).is_some() || *character == '.',
+        [] => false
+    }
+}
+
+// Number of characters making up the literal at the start of `characters`.
+// This is a superset of the valid literals, which are checked afterwards so
+// that malformed ones like `1.2.3` are reported instead of split apart.
+fn literal_length(characters: &[(usize, char)], options: &TokenizerOptions) -> usize {
+    let prefix: String = characters.iter().take(2).map(|&(_, character)| character).collect();
+    if radix_prefix(&prefix).is_some() {
+        let digits_length: usize = characters[2..]
+            .iter()
+            .take_while(|&&(_, character)| character.is_ascii_alphanumeric() || options.is_separator(character))
+            .count();
+
+        return 2 + digits_length;
     }
 
-    let mut tokens: Vec<Token> = Vec::new();
-    let mut literal_buffer: Vec<Digit> = Vec::new();
+    let mut length: usize = 0;
 
-    for character in expression.chars() {
-        let digit: Option<Digit> = Digit::new(character);
+    while let Some(&(_, character)) = characters.get(length) {
+        let follows_exponent_mark: bool = length > 0 && matches!(characters[length - 1].1, 'e' | 'E');
+        let is_literal_character: bool = match character {
+            'e' | 'E' | '.' | 'i' => true,
+            '+' | '-' => follows_exponent_mark,
+            _ => Digit::new(character).is_some() || options.is_separator(character)
+        };
 
-        if let Some(digit) = digit {
-          literal_buffer.push(digit);
-          continue;
+        if !is_literal_character { break; }
+        length += 1;
+        // The imaginary unit suffix ends the literal.
+        if character == 'i' { break; }
+    }
+
+    length
+}
+
+// Literal text without its digit separators, each of which has to stand
+// between two digits: `1_000` is fine but not `1__000`, `1_` or `0x_FF`.
+fn strip_separators(
+    characters: &[(usize, char)],
+    span: Span,
+    options: &TokenizerOptions
+) -> Result<String, EvalError> {
+    let text: String = characters.iter().map(|&(_, character)| character).collect();
+    let radix: Radix = radix_prefix(&text).unwrap_or(Radix::Decimal);
+    let is_digit = |index: Option<usize>| {
+        index
+            .and_then(|index| characters.get(index))
+            .is_some_and(|&(_, character)| Digit::with_radix(character, radix).is_some())
+    };
+
+    for (index, &(offset, character)) in characters.iter().enumerate() {
+        if options.is_separator(character) && !(is_digit(index.checked_sub(1)) && is_digit(Some(index + 1))) {
+            let separator_span: Span = Span::new(offset, character.len_utf8(), span.line, span.column + index);
+            return Err(EvalError::MisplacedSeparator(separator_span));
         }
+    }
+
+    Ok(text.chars().filter(|&character| !options.is_separator(character)).collect())
+}
 
-        if !(literal_buffer.is_empty()) {
-            pop_literal_buffer_to_tokens(&mut literal_buffer, &mut tokens);
+pub fn tokenize(expression: &str) -> Result<Vec<Spanned<Token>>, EvalError> {
+    tokenize_with(expression, &TokenizerOptions::default())
+}
+
+pub fn tokenize_with(expression: &str, options: &TokenizerOptions) -> Result<Vec<Spanned<Token>>, EvalError> {
+    let characters: Vec<(usize, char)> = expression.char_indices().collect();
+    let mut tokens: Vec<Spanned<Token>> = Vec::new();
+    let mut index: usize = 0;
+    let mut line: usize = 1;
+    let mut column: usize = 1;
+
+    while let Some(&(offset, character)) = characters.get(index) {
+        if is_literal_start(&characters[index..]) {
+            let length: usize = literal_length(&characters[index..], options);
+            let end: usize = characters.get(index + length).map_or(expression.len(), |&(end, _)| end);
+            let span: Span = Span::new(offset, end - offset, line, column);
+            let text: String = strip_separators(&characters[index..index + length], span, options)?;
+
+            let literal: Literal = text
+                .parse()
+                .map_err(|_| EvalError::InvalidLiteral(Spanned::new(String::from(&expression[offset..end]), span)))?;
+            tokens.push(Spanned::new(Token::Literal(literal), span));
+
+            index += length;
+            column += length;
+            continue;
         }
 
-        let token: Option<Token> = Token::from_char(character);
+        if is_identifier_start(character) {
+            let length: usize = characters[index..]
+                .iter()
+                .take_while(|&&(_, character)| is_identifier_character(character))
+                .count();
+            let end: usize = characters.get(index + length).map_or(expression.len(), |&(end, _)| end);
+            let span: Span = Span::new(offset, end - offset, line, column);
+            let word: &str = &expression[offset..end];
+            let token: Token = Operator::from_word(word).map_or_else(|| Token::Identifier(String::from(word)), Token::Operator);
+            tokens.push(Spanned::new(token, span));
 
-        if let Some(token) = token { tokens.push(token) };
-    }
+            index += length;
+            column += length;
+            continue;
+        }
+
+        // After an operand, `!==` is a factorial followed by `==`, as in `3!==6`.
+        let factorial_then_equal: bool = expression[offset..].starts_with("!==")
+            && !Token::is_prefix_position(tokens.last().map(|token| &token.node));
+        if let Some((operator, length)) = Operator::from_prefix(&expression[offset..]).filter(|_| !factorial_then_equal) {
+            let end: usize = characters.get(index + length).map_or(expression.len(), |&(end, _)| end);
+            let span: Span = Span::new(offset, end - offset, line, column);
+            tokens.push(Spanned::new(Token::Operator(operator), span));
+
+            index += length;
+            column += length;
+            continue;
+        }
+
+        let span: Span = Span::new(offset, character.len_utf8(), line, column);
 
-    // If the expression finishes with a literal, the buffer will contain it
-    if !(literal_buffer.is_empty()) {
-        pop_literal_buffer_to_tokens(&mut literal_buffer, &mut tokens);
+        if character == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+        index += 1;
+
+        let prefix_position: bool = Token::is_prefix_position(tokens.last().map(|token| &token.node));
+        let token: Option<Token> = match Token::from_char(character) {
+            Some(Token::Operator(operator)) => {
+                let unary_operator: Option<UnaryOperator> = if prefix_position {
+                    UnaryOperator::from_operator(&operator)
+                } else { None };
+
+                Some(unary_operator.map_or(Token::Operator(operator), Token::UnaryOperator))
+            },
+            // A `!` with no left operand is a negation, not a factorial.
+            Some(Token::UnaryOperator(UnaryOperator::Factorial)) if prefix_position => {
+                Some(Token::UnaryOperator(UnaryOperator::Not))
+            },
+            token => token
+        };
+
+        match token {
+            Some(token) => tokens.push(Spanned::new(token, span)),
+            None if character.is_whitespace() => {},
+            None => return Err(EvalError::UnexpectedCharacter(Spanned::new(character, span)))
+        }
     }
 
-    tokens
+    Ok(tokens)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use super::token::literal::Literal;
-    use super::token::operator::Operator;
 
     #[test]
     fn tokenize_creates_tokens_from_a_string() {
         let string = "2*3";
-        let result: Vec<Token> = tokenize(string);
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("2"))),
@@ -63,7 +219,10 @@ mod tests {
     #[test]
     fn tokenize_deals_with_multiple_digits_numbers() {
         let string = "22*33";
-        let result: Vec<Token> = tokenize(string);
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("22"))),
@@ -77,7 +236,10 @@ mod tests {
     #[test]
     fn tokenize_deals_with_parentheses() {
         let string = "2*(4-3)";
-        let result: Vec<Token> = tokenize(string);
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
 
         let expected_result: Vec<Token> = [
             Token::Literal(Literal::from(String::from("2"))),
@@ -91,4 +253,327 @@ mod tests {
 
        assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn tokenize_deals_with_modulo_integer_division_and_factorial() {
+        let string = "5!-7//2%3";
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
+
+        let expected_result: Vec<Token> = [
+            Token::Literal(Literal::from(String::from("5"))),
+            Token::UnaryOperator(UnaryOperator::Factorial),
+            Token::Operator(Operator::Minus),
+            Token::Literal(Literal::from(String::from("7"))),
+            Token::Operator(Operator::DoubleSlash),
+            Token::Literal(Literal::from(String::from("2"))),
+            Token::Operator(Operator::Percent),
+            Token::Literal(Literal::from(String::from("3")))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_reads_a_factorial_followed_by_equal() {
+        let string = "3!==6";
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
+
+        let expected_result: Vec<Token> = [
+            Token::Literal(Literal::from(String::from("3"))),
+            Token::UnaryOperator(UnaryOperator::Factorial),
+            Token::Operator(Operator::Equal),
+            Token::Literal(Literal::from(String::from("6")))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_comparisons_and_logical_operators() {
+        let string = "!(a<=1)||b!=2?c:!d";
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
+
+        let expected_result: Vec<Token> = [
+            Token::UnaryOperator(UnaryOperator::Not),
+            Token::LeftParenthesis,
+            Token::Identifier(String::from("a")),
+            Token::Operator(Operator::LessEqual),
+            Token::Literal(Literal::from(String::from("1"))),
+            Token::RightParenthesis,
+            Token::Operator(Operator::Or),
+            Token::Identifier(String::from("b")),
+            Token::Operator(Operator::NotEqual),
+            Token::Literal(Literal::from(String::from("2"))),
+            Token::QuestionMark,
+            Token::Identifier(String::from("c")),
+            Token::Colon,
+            Token::UnaryOperator(UnaryOperator::Not),
+            Token::Identifier(String::from("d"))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_bitwise_operators() {
+        let string = "a xor ~b<<2&c|xor_mask>>1";
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
+
+        let expected_result: Vec<Token> = [
+            Token::Identifier(String::from("a")),
+            Token::Operator(Operator::Xor),
+            Token::UnaryOperator(UnaryOperator::BitNot),
+            Token::Identifier(String::from("b")),
+            Token::Operator(Operator::ShiftLeft),
+            Token::Literal(Literal::from(String::from("2"))),
+            Token::Operator(Operator::BitAnd),
+            Token::Identifier(String::from("c")),
+            Token::Operator(Operator::BitOr),
+            Token::Identifier(String::from("xor_mask")),
+            Token::Operator(Operator::ShiftRight),
+            Token::Literal(Literal::from(String::from("1")))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_reads_both_spellings_of_approximate_equality() {
+        let result: Vec<Spanned<Token>> = tokenize("x~=1 ≈ y").unwrap();
+
+        let expected_result: Vec<Spanned<Token>> = [
+            Spanned::new(Token::Identifier(String::from("x")), Span::new(0, 1, 1, 1)),
+            Spanned::new(Token::Operator(Operator::ApproximatelyEqual), Span::new(1, 2, 1, 2)),
+            Spanned::new(Token::Literal(Literal::from(String::from("1"))), Span::new(3, 1, 1, 4)),
+            Spanned::new(Token::Operator(Operator::ApproximatelyEqual), Span::new(5, 3, 1, 6)),
+            Spanned::new(Token::Identifier(String::from("y")), Span::new(9, 1, 1, 8))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_distinguishes_prefix_operators() {
+        let string = "-2*-(+3-1)";
+        let result: Vec<Token> = tokenize(string).unwrap()
+            .into_iter()
+            .map(|token| token.node)
+            .collect();
+
+        let expected_result: Vec<Token> = [
+            Token::UnaryOperator(UnaryOperator::Minus),
+            Token::Literal(Literal::from(String::from("2"))),
+            Token::Operator(Operator::Times),
+            Token::UnaryOperator(UnaryOperator::Minus),
+            Token::LeftParenthesis,
+            Token::UnaryOperator(UnaryOperator::Plus),
+            Token::Literal(Literal::from(String::from("3"))),
+            Token::Operator(Operator::Minus),
+            Token::Literal(Literal::from(String::from("1"))),
+            Token::RightParenthesis
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_decimal_and_scientific_literals() {
+        let string = "1.5*.25+6.02e23-1E-9";
+        let result: Vec<String> = tokenize(string).unwrap()
+            .iter()
+            .map(|token| token.node.to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1.5", "*", ".25", "+", "6.02e23", "-", "1e-9"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_hexadecimal_binary_and_octal_literals() {
+        let string = "0x1E-0b1010*0o17";
+        let result: Vec<String> = tokenize(string).unwrap()
+            .iter()
+            .map(|token| token.node.to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["0x1E", "-", "0b1010", "*", "0o17"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_digit_separators() {
+        let string = "1_000_000+0xFF_FF";
+        let result: Vec<Spanned<String>> = tokenize(string).unwrap()
+            .iter()
+            .map(|token| Spanned::new(token.node.to_string(), token.span))
+            .collect();
+
+        let expected_result: Vec<Spanned<String>> = [
+            Spanned::new(String::from("1000000"), Span::new(0, 9, 1, 1)),
+            Spanned::new(String::from("+"), Span::new(9, 1, 1, 10)),
+            Spanned::new(String::from("0xFFFF"), Span::new(10, 7, 1, 11))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_locale_digit_separators_on_demand() {
+        let options: TokenizerOptions = TokenizerOptions { locale_separators: true };
+        let result: Vec<String> = ["1'000'000", "1\u{2009}000.5"]
+            .iter()
+            .map(|string| tokenize_with(string, &options).unwrap()[0].node.to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1000000", "1000.5"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+        assert_eq!(tokenize("1'000"), Err(EvalError::UnexpectedCharacter(Spanned::new('\'', Span::new(1, 1, 1, 2)))));
+    }
+
+    #[test]
+    fn tokenize_rejects_unknown_characters() {
+        let result: Vec<Result<Vec<Spanned<Token>>, EvalError>> = ["1 $ + 2", "1 + 2 #", "2 €"]
+            .iter()
+            .map(|string| tokenize(string))
+            .collect();
+
+        let expected_result: Vec<Result<Vec<Spanned<Token>>, EvalError>> = [
+            Err(EvalError::UnexpectedCharacter(Spanned::new('$', Span::new(2, 1, 1, 3)))),
+            Err(EvalError::UnexpectedCharacter(Spanned::new('#', Span::new(6, 1, 1, 7)))),
+            Err(EvalError::UnexpectedCharacter(Spanned::new('€', Span::new(2, 3, 1, 3))))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+        assert_eq!(tokenize(" 1\t+\u{2009}2 ").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn tokenize_rejects_misplaced_digit_separators() {
+        let result: Vec<Result<Vec<Spanned<Token>>, EvalError>> = ["1__0", "12_", "0x_FF", "1_.5", "1._5", "1e_5"]
+            .iter()
+            .map(|string| tokenize(string))
+            .collect();
+
+        let expected_result: Vec<Result<Vec<Spanned<Token>>, EvalError>> = [
+            Err(EvalError::MisplacedSeparator(Span::new(1, 1, 1, 2))),
+            Err(EvalError::MisplacedSeparator(Span::new(2, 1, 1, 3))),
+            Err(EvalError::MisplacedSeparator(Span::new(2, 1, 1, 3))),
+            Err(EvalError::MisplacedSeparator(Span::new(1, 1, 1, 2))),
+            Err(EvalError::MisplacedSeparator(Span::new(2, 1, 1, 3))),
+            Err(EvalError::MisplacedSeparator(Span::new(2, 1, 1, 3)))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_imaginary_literals() {
+        let string = "(1+2i)*(3-i)";
+        let result: Vec<String> = tokenize(string).unwrap()
+            .iter()
+            .map(|token| token.node.to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["(", "1", "+", "2i", ")", "*", "(", "3", "-", "i", ")"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_uncertainties() {
+        let string = "9.81±0.02 * 2 +/- 0.1";
+        let result: Vec<Spanned<Token>> = tokenize(string).unwrap();
+
+        let expected_result: Vec<Spanned<Token>> = [
+            Spanned::new(Token::Literal(Literal::from(String::from("9.81"))), Span::new(0, 4, 1, 1)),
+            Spanned::new(Token::Operator(Operator::PlusMinus), Span::new(4, 2, 1, 5)),
+            Spanned::new(Token::Literal(Literal::from(String::from("0.02"))), Span::new(6, 4, 1, 6)),
+            Spanned::new(Token::Operator(Operator::Times), Span::new(11, 1, 1, 11)),
+            Spanned::new(Token::Literal(Literal::from(String::from("2"))), Span::new(13, 1, 1, 13)),
+            Spanned::new(Token::Operator(Operator::PlusMinus), Span::new(15, 3, 1, 15)),
+            Spanned::new(Token::Literal(Literal::from(String::from("0.1"))), Span::new(19, 3, 1, 19))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_deals_with_identifiers() {
+        let string = "x_1*2 + rate+i";
+        let result: Vec<Spanned<Token>> = tokenize(string).unwrap();
+
+        let expected_result: Vec<Spanned<Token>> = [
+            Spanned::new(Token::Identifier(String::from("x_1")), Span::new(0, 3, 1, 1)),
+            Spanned::new(Token::Operator(Operator::Times), Span::new(3, 1, 1, 4)),
+            Spanned::new(Token::Literal(Literal::from(String::from("2"))), Span::new(4, 1, 1, 5)),
+            Spanned::new(Token::Operator(Operator::Plus), Span::new(6, 1, 1, 7)),
+            Spanned::new(Token::Identifier(String::from("rate")), Span::new(8, 4, 1, 9)),
+            Spanned::new(Token::Operator(Operator::Plus), Span::new(12, 1, 1, 13)),
+            Spanned::new(Token::Identifier(String::from("i")), Span::new(13, 1, 1, 14))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_rejects_malformed_literals() {
+        let result: Vec<Result<Vec<Spanned<Token>>, EvalError>> = ["2+1.2.3", "0b102"]
+            .iter()
+            .map(|string| tokenize(string))
+            .collect();
+
+        let expected_result: Vec<Result<Vec<Spanned<Token>>, EvalError>> = [
+            Err(EvalError::InvalidLiteral(Spanned::new(String::from("1.2.3"), Span::new(2, 5, 1, 3)))),
+            Err(EvalError::InvalidLiteral(Spanned::new(String::from("0b102"), Span::new(0, 5, 1, 1))))
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn tokenize_attaches_a_span_to_each_token() {
+        let string = "12 *\n(3)";
+        let result: Vec<Span> = tokenize(string).unwrap()
+            .iter()
+            .map(|token| token.span)
+            .collect();
+
+        let expected_result: Vec<Span> = [
+            Span::new(0, 2, 1, 1),
+            Span::new(3, 1, 1, 4),
+            Span::new(4, 1, 1, 5),
+            Span::new(5, 1, 2, 1),
+            Span::new(6, 1, 2, 2),
+            Span::new(7, 1, 2, 3)
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
 }
@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter, Error};
+
+// Position of a piece of the source expression: `offset` and `length` are
+// in bytes, `line` and `column` start at 1 and count characters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Span {
+    pub offset: usize,
+    pub length: usize,
+    pub line: usize,
+    pub column: usize
+}
+
+impl Span {
+    pub fn new(offset: usize, length: usize, line: usize, column: usize) -> Span {
+        Span { offset, length, line, column }
+    }
+
+    pub fn end(&self) -> usize {
+        self.offset + self.length
+    }
+
+    // Smallest span covering both `self` and `other`, `other` being further
+    // in the expression.
+    pub fn to(&self, other: &Span) -> Span {
+        Span::new(self.offset, other.end() - self.offset, self.line, self.column)
+    }
+}
+
+impl Default for Span {
+    fn default() -> Span {
+        Span::new(0, 0, 1, 1)
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spanned<T> {
+    pub node: T,
+    pub span: Span
+}
+
+impl<T> Spanned<T> {
+    pub fn new(node: T, span: Span) -> Spanned<T> {
+        Spanned { node, span }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_can_be_merged() {
+        let first: Span = Span::new(2, 1, 1, 3);
+        let second: Span = Span::new(5, 2, 1, 6);
+
+        let result: Span = first.to(&second);
+
+        let expected_result: Span = Span::new(2, 5, 1, 3);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn spans_are_represented_as_line_and_column() {
+        let result: String = Span::new(7, 1, 2, 4).to_string();
+
+        assert_eq!(result, "2:4");
+    }
+}
@@ -1,10 +1,19 @@
 use std::fmt::{Debug, Display, Formatter, Error};
 
+use super::radix::Radix;
+
 #[derive(Clone)]
 pub enum Digit {
-    Zero, One, Two, Three, Four, Five, Six, Seven, Eight, Nine
+    Zero, One, Two, Three, Four, Five, Six, Seven, Eight, Nine,
+    A, B, C, D, E, F
 }
 
+const DIGITS: [Digit; 16] = [
+    Digit::Zero, Digit::One, Digit::Two, Digit::Three, Digit::Four,
+    Digit::Five, Digit::Six, Digit::Seven, Digit::Eight, Digit::Nine,
+    Digit::A, Digit::B, Digit::C, Digit::D, Digit::E, Digit::F
+];
+
 impl Digit {
     pub fn new(c: char) -> Option<Digit> {
         match c {
@@ -22,6 +31,20 @@ impl Digit {
         }
     }
 
+    // Like `new`, but for digits of any `radix`: `Digit::with_radix('f',
+    // Radix::Hexadecimal)` is `Digit::F` whereas `'2'` is no binary digit.
+    pub fn with_radix(c: char, radix: Radix) -> Option<Digit> {
+        c.to_digit(radix.base()).and_then(Digit::from_value)
+    }
+
+    pub fn from_value(value: u32) -> Option<Digit> {
+        DIGITS.get(value as usize).cloned()
+    }
+
+    pub fn value(&self) -> u32 {
+        self.to_char().to_digit(16).unwrap()
+    }
+
     pub fn to_char(&self) -> char {
         match *self {
             Digit::Zero => '0',
@@ -34,6 +57,12 @@ impl Digit {
             Digit::Seven => '7',
             Digit::Eight => '8',
             Digit::Nine => '9',
+            Digit::A => 'A',
+            Digit::B => 'B',
+            Digit::C => 'C',
+            Digit::D => 'D',
+            Digit::E => 'E',
+            Digit::F => 'F'
         }
     }
 }
@@ -56,7 +85,13 @@ impl Debug for Digit {
             Digit::Six => "Digit::Six",
             Digit::Seven => "Digit::Seven",
             Digit::Eight => "Digit::Eight",
-            Digit::Nine => "Digit::Nine"
+            Digit::Nine => "Digit::Nine",
+            Digit::A => "Digit::A",
+            Digit::B => "Digit::B",
+            Digit::C => "Digit::C",
+            Digit::D => "Digit::D",
+            Digit::E => "Digit::E",
+            Digit::F => "Digit::F"
         };
         write!(f, "{} ({})", enum_name, self.to_char())
     }
@@ -68,6 +103,8 @@ impl PartialEq for Digit {
     }
 }
 
+impl Eq for Digit {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,9 +125,7 @@ mod tests {
     fn operators_can_be_instanciated_from_characters() {
         let result: Vec<Digit> = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']
             .iter()
-            .map(|c| Digit::new(*c))
-            .filter(|c| c.is_some())
-            .map(|c| c.unwrap())
+            .filter_map(|c| Digit::new(*c))
             .collect();
 
         let expected_result: [Digit; 10] = [
@@ -105,11 +140,48 @@ mod tests {
     fn operator_constructor_return_none_if_character_is_not_an_operator() {
         let result: Vec<Digit> = ['a', '+', ',']
             .iter()
-            .map(|c| Digit::new(*c))
-            .filter(|c| c.is_some())
-            .map(|c| c.unwrap())
+            .filter_map(|c| Digit::new(*c))
+            .collect();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn digits_can_be_instanciated_in_any_radix() {
+        let result: Vec<Option<Digit>> = [
+            ('1', Radix::Binary),
+            ('2', Radix::Binary),
+            ('7', Radix::Octal),
+            ('8', Radix::Octal),
+            ('9', Radix::Decimal),
+            ('a', Radix::Decimal),
+            ('a', Radix::Hexadecimal),
+            ('F', Radix::Hexadecimal),
+            ('g', Radix::Hexadecimal)
+        ]
+            .iter()
+            .map(|&(c, radix)| Digit::with_radix(c, radix))
             .collect();
 
-        assert_eq!(result.is_empty(), true);
+        let expected_result: [Option<Digit>; 9] = [
+            Some(Digit::One), None,
+            Some(Digit::Seven), None,
+            Some(Digit::Nine), None,
+            Some(Digit::A), Some(Digit::F), None
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn each_digit_has_a_value() {
+        let result: Vec<u32> = [Digit::Zero, Digit::Nine, Digit::A, Digit::F]
+            .iter()
+            .map(|digit| digit.value())
+            .collect();
+
+        let expected_result: [u32; 4] = [0, 9, 10, 15];
+
+        assert_eq!(result, expected_result);
     }
 }
@@ -1,43 +1,174 @@
 use std::fmt::{Display, Formatter, Error};
+use std::str::FromStr;
 
 pub mod digit;
 use self::digit::Digit;
 
-#[derive(Clone, Debug)]
-pub struct Literal(Vec<Digit>);
+pub mod radix;
+use self::radix::Radix;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exponent {
+    pub negative: bool,
+    pub digits: Vec<Digit>
+}
+
+// A number as written in the expression: `12`, `1.5`, `.25`, `6.02e23` or,
+// for integers only, `0x1F`, `0o17` and `0b1010`. Any of them followed by
+// `i` is imaginary, a lone `i` being the imaginary unit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Literal {
+    radix: Radix,
+    integer: Vec<Digit>,
+    fraction: Option<Vec<Digit>>,
+    exponent: Option<Exponent>,
+    imaginary: bool
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParseLiteralError;
 
 impl Literal {
     pub fn new(digits: Vec<Digit>) -> Literal {
-        Literal(digits)
+        Literal::with_radix(Radix::Decimal, digits)
+    }
+
+    pub fn with_radix(radix: Radix, digits: Vec<Digit>) -> Literal {
+        Literal { radix, integer: digits, fraction: None, exponent: None, imaginary: false }
+    }
+
+    // The lone `i`.
+    pub fn imaginary_unit() -> Literal {
+        Literal { imaginary: true, ..Literal::new(Vec::new()) }
+    }
+
+    pub fn is_imaginary(&self) -> bool {
+        self.imaginary
+    }
+
+    // Real literal multiplied by the imaginary unit in an imaginary literal:
+    // `2.5` for `2.5i` and `1` for `i`.
+    pub fn coefficient(&self) -> Literal {
+        if self.integer.is_empty() && self.fraction.is_none() {
+            return Literal::new([Digit::One].to_vec());
+        }
+
+        Literal { imaginary: false, ..self.clone() }
+    }
+
+    pub fn radix(&self) -> Radix {
+        self.radix
+    }
+
+    pub fn integer(&self) -> &[Digit] {
+        &self.integer
+    }
+
+    pub fn fraction(&self) -> Option<&[Digit]> {
+        self.fraction.as_deref()
+    }
+
+    pub fn exponent(&self) -> Option<&Exponent> {
+        self.exponent.as_ref()
+    }
+}
+
+fn parse_digits(string: &str, radix: Radix) -> Result<Vec<Digit>, ParseLiteralError> {
+    string
+        .chars()
+        .map(|c| Digit::with_radix(c, radix).ok_or(ParseLiteralError))
+        .collect()
+}
+
+// Radix announced by the first two characters of `string`, if any.
+pub fn radix_prefix(string: &str) -> Option<Radix> {
+    let mut characters = string.chars();
+
+    match (characters.next(), characters.next()) {
+        (Some('0'), Some(c)) => Radix::from_prefix_char(c),
+        _ => None
+    }
+}
+
+impl FromStr for Literal {
+    type Err = ParseLiteralError;
+
+    fn from_str(string: &str) -> Result<Literal, ParseLiteralError> {
+        if string == "i" {
+            return Ok(Literal::imaginary_unit());
+        }
+        if let Some(coefficient) = string.strip_suffix('i') {
+            let literal: Literal = coefficient.parse()?;
+            if literal.imaginary { return Err(ParseLiteralError); }
+
+            return Ok(Literal { imaginary: true, ..literal });
+        }
+
+        if let Some(radix) = radix_prefix(string) {
+            let digits: Vec<Digit> = parse_digits(&string[2..], radix)?;
+            if digits.is_empty() { return Err(ParseLiteralError); }
+
+            return Ok(Literal::with_radix(radix, digits));
+        }
+
+        let (mantissa, exponent): (&str, Option<&str>) = match string.find(['e', 'E']) {
+            Some(index) => (&string[..index], Some(&string[index + 1..])),
+            None => (string, None)
+        };
+        let (integer, fraction): (&str, Option<&str>) = match mantissa.find('.') {
+            Some(index) => (&mantissa[..index], Some(&mantissa[index + 1..])),
+            None => (mantissa, None)
+        };
+
+        let integer: Vec<Digit> = parse_digits(integer, Radix::Decimal)?;
+        let fraction: Option<Vec<Digit>> = match fraction {
+            Some("") => return Err(ParseLiteralError),
+            Some(fraction) => Some(parse_digits(fraction, Radix::Decimal)?),
+            None => None
+        };
+        if integer.is_empty() && fraction.is_none() { return Err(ParseLiteralError); }
+
+        let exponent: Option<Exponent> = match exponent {
+            Some(exponent) => {
+                let negative: bool = exponent.starts_with('-');
+                let digits: &str = exponent.trim_start_matches(['+', '-']);
+                if digits.is_empty() || exponent.len() - digits.len() > 1 {
+                    return Err(ParseLiteralError);
+                }
+
+                Some(Exponent { negative, digits: parse_digits(digits, Radix::Decimal)? })
+            },
+            None => None
+        };
+
+        Ok(Literal { radix: Radix::Decimal, integer, fraction, exponent, imaginary: false })
     }
 }
 
 impl Display for Literal {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        let string: String = self.0
-            .iter()
-            .map(|digit| digit.to_char())
-            .collect();
-        write!(f, "{}", string)
+        fn to_string(digits: &[Digit]) -> String {
+            digits.iter().map(|digit| digit.to_char()).collect()
+        }
+
+        write!(f, "{}{}", self.radix, to_string(&self.integer))?;
+        if let Some(ref fraction) = self.fraction {
+            write!(f, ".{}", to_string(fraction))?;
+        }
+        if let Some(ref exponent) = self.exponent {
+            write!(f, "e{}{}", if exponent.negative { "-" } else { "" }, to_string(&exponent.digits))?;
+        }
+        if self.imaginary {
+            write!(f, "i")?;
+        }
+        Ok(())
     }
 }
 
+// Meant for literals known to be well formed, panics otherwise.
 impl From<String> for Literal {
     fn from(string: String) -> Self {
-        Literal::new(
-            string
-                .chars()
-                .map(Digit::new)
-                .filter(|c| c.is_some())
-                .map(|c| c.unwrap())
-                .collect()
-        )
-    }
-}
-
-impl PartialEq for Literal {
-    fn eq(&self, other: &Literal) -> bool {
-        self.0 == other.0
+        string.parse().unwrap_or_else(|_| panic!("invalid literal `{}`", string))
     }
 }
 
@@ -55,7 +186,7 @@ mod tests {
         ]
             .iter()
             .cloned()
-            .map(|vector| Literal::new(vector))
+            .map(Literal::new)
             .map(|literal| literal.to_string())
             .collect();
 
@@ -72,7 +203,7 @@ mod tests {
         let result: Vec<Literal> = ["1", "23", "456", "7890"]
             .iter()
             .map(|&string| String::from(string))
-            .map(|string| Literal::from(string))
+            .map(Literal::from)
             .collect();
 
         let expected_result: Vec<Literal> = [
@@ -83,9 +214,101 @@ mod tests {
         ]
             .iter()
             .cloned()
-            .map(|vector| Literal::new(vector))
+            .map(Literal::new)
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn literals_can_have_a_fractional_part_and_an_exponent() {
+        let result: Literal = "6.02E-23".parse().unwrap();
+
+        assert_eq!(result.integer(), [Digit::Six]);
+        assert_eq!(result.fraction(), Some([Digit::Zero, Digit::Two].as_ref()));
+        assert_eq!(
+            result.exponent(),
+            Some(&Exponent { negative: true, digits: [Digit::Two, Digit::Three].to_vec() })
+        );
+    }
+
+    #[test]
+    fn decimal_literals_can_be_represented_as_strings() {
+        let result: Vec<String> = ["1.5", ".25", "6.02e23", "1E-9", "2e+3"]
+            .iter()
+            .map(|string| string.parse::<Literal>().unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["1.5", ".25", "6.02e23", "1e-9", "2e3"]
+            .iter()
+            .map(|&string| String::from(string))
             .collect();
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn integer_literals_can_be_written_in_other_radixes() {
+        let result: Vec<Literal> = ["0x1f", "0b1010", "0O17"]
+            .iter()
+            .map(|string| string.parse().unwrap())
+            .collect();
+
+        let expected_result: [Literal; 3] = [
+            Literal::with_radix(Radix::Hexadecimal, [Digit::One, Digit::F].to_vec()),
+            Literal::with_radix(Radix::Binary, [Digit::One, Digit::Zero, Digit::One, Digit::Zero].to_vec()),
+            Literal::with_radix(Radix::Octal, [Digit::One, Digit::Seven].to_vec())
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn literals_keep_their_radix_when_represented_as_strings() {
+        let result: Vec<String> = ["0x1F", "0b1010", "0o17"]
+            .iter()
+            .map(|string| string.parse::<Literal>().unwrap().to_string())
+            .collect();
+
+        let expected_result: Vec<String> = ["0x1F", "0b1010", "0o17"]
+            .iter()
+            .map(|&string| String::from(string))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn imaginary_literals_have_a_real_coefficient() {
+        let result: Vec<(bool, String, String)> = ["2i", "1.5e3i", "0x1Fi", "i", "3"]
+            .iter()
+            .map(|string| string.parse::<Literal>().unwrap())
+            .map(|literal| (literal.is_imaginary(), literal.to_string(), literal.coefficient().to_string()))
+            .collect();
+
+        let expected_result: Vec<(bool, String, String)> = [
+            (true, "2i", "2"),
+            (true, "1.5e3i", "1.5e3"),
+            (true, "0x1Fi", "0x1F"),
+            (true, "i", "1"),
+            (false, "3", "3")
+        ]
+            .iter()
+            .map(|&(imaginary, literal, coefficient)| (imaginary, String::from(literal), String::from(coefficient)))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn malformed_literals_are_rejected() {
+        let result: Vec<Result<Literal, ParseLiteralError>> = [
+            "1.2.3", ".", "1.", "1e", "1e+-2", "1e2e3", "1e2.5", "0x", "0b102", "0o8", "0x1.5", "12a", "2ii", ".i"
+        ]
+            .iter()
+            .map(|string| string.parse::<Literal>())
+            .collect();
+
+        assert!(result.iter().all(|literal| *literal == Err(ParseLiteralError)));
+    }
 }
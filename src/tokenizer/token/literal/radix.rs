@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter, Error};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Radix {
+    Binary, Octal, Decimal, Hexadecimal
+}
+
+impl Radix {
+    pub fn base(&self) -> u32 {
+        match *self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hexadecimal => 16
+        }
+    }
+
+    // Letter following the leading `0` of a literal written in this radix.
+    pub fn from_prefix_char(c: char) -> Option<Radix> {
+        match c {
+            'b' | 'B' => Some(Radix::Binary),
+            'o' | 'O' => Some(Radix::Octal),
+            'x' | 'X' => Some(Radix::Hexadecimal),
+            _ => None
+        }
+    }
+
+    pub fn prefix(&self) -> &'static str {
+        match *self {
+            Radix::Binary => "0b",
+            Radix::Octal => "0o",
+            Radix::Decimal => "",
+            Radix::Hexadecimal => "0x"
+        }
+    }
+}
+
+impl Display for Radix {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", self.prefix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radixes_can_be_instanciated_from_their_prefix() {
+        let result: Vec<Option<Radix>> = ['b', 'O', 'x', 'd']
+            .iter()
+            .map(|c| Radix::from_prefix_char(*c))
+            .collect();
+
+        let expected_result: [Option<Radix>; 4] = [
+            Some(Radix::Binary),
+            Some(Radix::Octal),
+            Some(Radix::Hexadecimal),
+            None
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn each_radix_has_a_base_and_a_prefix() {
+        let result: Vec<(u32, &str)> = [Radix::Binary, Radix::Octal, Radix::Decimal, Radix::Hexadecimal]
+            .iter()
+            .map(|radix| (radix.base(), radix.prefix()))
+            .collect();
+
+        let expected_result: [(u32, &str); 4] = [(2, "0b"), (8, "0o"), (10, ""), (16, "0x")];
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -6,6 +6,9 @@ use self::operator::Operator;
 pub mod literal;
 use self::literal::Literal;
 
+pub mod unary_operator;
+use self::unary_operator::UnaryOperator;
+
 fn is_left_parenthesis(c: char) -> bool {
     c == '('
 }
@@ -17,9 +20,20 @@ fn is_right_parenthesis(c: char) -> bool {
 #[derive(Clone, PartialEq)]
 pub enum Token {
     Literal(Literal),
+    Identifier(String),
     Operator(Operator),
+    UnaryOperator(UnaryOperator),
     LeftParenthesis,
-    RightParenthesis
+    RightParenthesis,
+    LeftBracket,
+    RightBracket,
+    Comma,
+    Equals,
+    Semicolon,
+    Newline,
+    // Separators of a conditional, `condition ? value : other_value`.
+    QuestionMark,
+    Colon
 }
 
 impl Display for Token {
@@ -27,8 +41,18 @@ impl Display for Token {
         match *self {
             Token::LeftParenthesis => write!(f, "("),
             Token::RightParenthesis => write!(f, ")"),
-            Token::Literal(ref literal) => <Literal as Display>::fmt(&literal, f),
-            Token::Operator(ref operator) => <Operator as Display>::fmt(&operator, f)
+            Token::LeftBracket => write!(f, "["),
+            Token::RightBracket => write!(f, "]"),
+            Token::Comma => write!(f, ","),
+            Token::Equals => write!(f, "="),
+            Token::Semicolon => write!(f, ";"),
+            Token::Newline => writeln!(f),
+            Token::QuestionMark => write!(f, "?"),
+            Token::Colon => write!(f, ":"),
+            Token::Literal(ref literal) => <Literal as Display>::fmt(literal, f),
+            Token::Identifier(ref name) => write!(f, "{}", name),
+            Token::Operator(ref operator) => <Operator as Display>::fmt(operator, f),
+            Token::UnaryOperator(ref operator) => <UnaryOperator as Display>::fmt(operator, f)
         }
     }
 }
@@ -38,8 +62,18 @@ impl Debug for Token {
         match *self {
             Token::LeftParenthesis => write!(f, "LeftParenthesis ("),
             Token::RightParenthesis => write!(f, "RightParenthesis )"),
-            Token::Literal(ref literal) => <Literal as Debug>::fmt(&literal, f),
-            Token::Operator(ref operator) => <Operator as Debug>::fmt(&operator, f)
+            Token::LeftBracket => write!(f, "LeftBracket ["),
+            Token::RightBracket => write!(f, "RightBracket ]"),
+            Token::Comma => write!(f, "Comma ,"),
+            Token::Equals => write!(f, "Equals ="),
+            Token::Semicolon => write!(f, "Semicolon ;"),
+            Token::Newline => write!(f, "Newline"),
+            Token::QuestionMark => write!(f, "QuestionMark ?"),
+            Token::Colon => write!(f, "Colon :"),
+            Token::Literal(ref literal) => <Literal as Debug>::fmt(literal, f),
+            Token::Identifier(ref name) => write!(f, "Identifier {}", name),
+            Token::Operator(ref operator) => <Operator as Debug>::fmt(operator, f),
+            Token::UnaryOperator(ref operator) => <UnaryOperator as Debug>::fmt(operator, f)
         }
     }
 }
@@ -52,16 +86,42 @@ impl Token {
             },
             character if is_right_parenthesis(character) => {
                 Some(Token::RightParenthesis)
-            }
+            },
+            '[' => Some(Token::LeftBracket),
+            ']' => Some(Token::RightBracket),
+            ',' => Some(Token::Comma),
+            '=' => Some(Token::Equals),
+            ';' => Some(Token::Semicolon),
+            '\n' => Some(Token::Newline),
+            '!' => Some(Token::UnaryOperator(UnaryOperator::Factorial)),
+            '~' => Some(Token::UnaryOperator(UnaryOperator::BitNot)),
+            '?' => Some(Token::QuestionMark),
+            ':' => Some(Token::Colon),
             _ => {
                 let operator: Option<Operator> = Operator::new(character);
-                match operator {
-                    Some(operator) => Some(Token::Operator(operator)),
-                    None => None
-                }
+                operator.map(Token::Operator)
             }
         }
     }
+
+    // Whether an operator following `previous` is in prefix position, i.e.
+    // has no left operand.
+    pub fn is_prefix_position(previous: Option<&Token>) -> bool {
+        match previous {
+            None => true,
+            Some(&Token::Operator(_)) => true,
+            Some(Token::UnaryOperator(operator)) => !operator.is_postfix(),
+            Some(&Token::LeftParenthesis) => true,
+            Some(&Token::LeftBracket) => true,
+            Some(&Token::Comma) => true,
+            Some(&Token::Equals) => true,
+            Some(&Token::Semicolon) => true,
+            Some(&Token::Newline) => true,
+            Some(&Token::QuestionMark) => true,
+            Some(&Token::Colon) => true,
+            Some(_) => false
+        }
+    }
 }
 
 #[cfg(test)]
@@ -70,10 +130,10 @@ mod tests {
 
     #[test]
     fn each_token_can_be_represented_as_a_string() {
-        let _1: String = String::from("1");
-        let _23: String = String::from("23");
-        let _456: String = String::from("456");
-        let _7890: String = String::from("7890");
+        let one: String = String::from("1");
+        let twenty_three: String = String::from("23");
+        let four_hundred_fifty_six: String = String::from("456");
+        let seven_thousand_eight_hundred_ninety: String = String::from("7890");
 
         let result: Vec<String> = [
             Token::Operator(Operator::Plus),
@@ -83,17 +143,22 @@ mod tests {
             Token::Operator(Operator::Caret),
             Token::LeftParenthesis,
             Token::RightParenthesis,
-            Token::Literal(Literal::from(_1)),
-            Token::Literal(Literal::from(_23)),
-            Token::Literal(Literal::from(_456)),
-            Token::Literal(Literal::from(_7890))
+            Token::LeftBracket,
+            Token::RightBracket,
+            Token::Comma,
+            Token::Equals,
+            Token::Semicolon,
+            Token::Literal(Literal::from(one)),
+            Token::Literal(Literal::from(twenty_three)),
+            Token::Literal(Literal::from(four_hundred_fifty_six)),
+            Token::Literal(Literal::from(seven_thousand_eight_hundred_ninety))
         ]
         .iter()
         .map(|token| token.to_string())
         .collect();
 
         let expected_result: Vec<String> =
-            ["+", "-", "*", "/", "^", "(", ")", "1", "23", "456", "7890"]
+            ["+", "-", "*", "/", "^", "(", ")", "[", "]", ",", "=", ";", "1", "23", "456", "7890"]
                 .iter()
                 .map(|string| String::from(*string))
                 .collect();
@@ -106,15 +171,13 @@ mod tests {
     // single characters.
     fn some_tokens_can_be_instanciated_from_a_character() {
         let result: Vec<Token> = [
-            '+', '-', '*', '/', '^', '(', ')'
+            '+', '-', '*', '/', '^', '(', ')', '[', ']', ',', '=', ';', '\n'
         ]
         .iter()
-        .map(|character| Token::from_char(*character))
-        .filter(|character| character.is_some())
-        .map(|character| character.unwrap())
+        .filter_map(|character| Token::from_char(*character))
         .collect();
 
-        let expected_result: [Token; 7] = [
+        let expected_result: [Token; 13] = [
             Token::Operator(Operator::Plus),
             Token::Operator(Operator::Minus),
             Token::Operator(Operator::Times),
@@ -122,6 +185,12 @@ mod tests {
             Token::Operator(Operator::Caret),
             Token::LeftParenthesis,
             Token::RightParenthesis,
+            Token::LeftBracket,
+            Token::RightBracket,
+            Token::Comma,
+            Token::Equals,
+            Token::Semicolon,
+            Token::Newline
         ];
 
         assert_eq!(result, expected_result);
@@ -1,24 +1,105 @@
-use std::ops::{Add, Div, Mul, Sub};
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter, Error};
 
-#[derive(Clone)]
+use number::{ArithmeticError, Number};
+use number::tolerance::Tolerance;
+
+#[derive(Clone, PartialEq)]
 pub enum Operator {
-    Plus, Minus, Times, Slash, Caret
+    Plus, Minus, PlusMinus, Times, Slash, DoubleSlash, Percent, Caret,
+    Less, LessEqual, Greater, GreaterEqual, Equal, NotEqual, ApproximatelyEqual,
+    And, Or,
+    BitAnd, BitOr, Xor, ShiftLeft, ShiftRight
+}
+
+// Operators spelled with several characters, as an alternative to a single
+// character one.
+const MULTI_CHARACTER_OPERATORS: [(&str, Operator); 11] = [
+    ("+/-", Operator::PlusMinus),
+    ("//", Operator::DoubleSlash),
+    ("<=", Operator::LessEqual),
+    (">=", Operator::GreaterEqual),
+    ("==", Operator::Equal),
+    ("!=", Operator::NotEqual),
+    ("~=", Operator::ApproximatelyEqual),
+    ("&&", Operator::And),
+    ("||", Operator::Or),
+    ("<<", Operator::ShiftLeft),
+    (">>", Operator::ShiftRight)
+];
+
+// Side on which operators of equal precedence are grouped: `3-2-1` is
+// `(3-2)-1` whereas `2^3^2` is `2^(3^2)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Associativity {
+    Left, Right
 }
 
 impl Operator {
-    pub fn call(&self, first_operand: f32, second_operand: f32) -> f32 {
-        let operator: fn(f32, f32) -> f32 = match *self {
-            Operator::Plus => <f32 as Add>::add,
-            Operator::Times => <f32 as Mul>::mul,
-            Operator::Minus => <f32 as Sub>::sub,
-            Operator::Slash => <f32 as Div>::div,
-            Operator::Caret => f32::powf,
+    // Arithmetic operation of the operator. Comparison and logical operators
+    // give booleans instead, see `compare` and `is_logical`.
+    pub fn call<N: Number>(&self, first_operand: N, second_operand: N) -> Result<N, ArithmeticError> {
+        let operator: fn(N, N) -> Result<N, ArithmeticError> = match *self {
+            Operator::Plus => N::plus,
+            Operator::Times => N::times,
+            Operator::Minus => N::minus,
+            Operator::PlusMinus => N::plus_minus,
+            Operator::Slash => N::divide,
+            Operator::DoubleSlash => N::integer_divide,
+            Operator::Percent => N::remainder,
+            Operator::Caret => N::power,
+            Operator::BitAnd => N::bit_and,
+            Operator::BitOr => N::bit_or,
+            Operator::Xor => N::bit_xor,
+            Operator::ShiftLeft => N::shift_left,
+            Operator::ShiftRight => N::shift_right,
+            _ => return Err(ArithmeticError::NotRepresentable)
         };
         operator(first_operand, second_operand)
     }
 
+    // Outcome of a comparison operator, `~=` allowing the operands to differ
+    // by `tolerance`.
+    pub fn compare<N: Number>(
+        &self,
+        first_operand: &N,
+        second_operand: &N,
+        tolerance: &Tolerance,
+        settings: &N::Settings
+    ) -> Result<bool, ArithmeticError> {
+        match *self {
+            Operator::Equal => first_operand.equals(second_operand),
+            Operator::NotEqual => first_operand.equals(second_operand).map(|equal| !equal),
+            Operator::ApproximatelyEqual => first_operand.approximately_equals(second_operand, tolerance, settings),
+            Operator::Less => first_operand.compare(second_operand).map(|ordering| ordering == Ordering::Less),
+            Operator::LessEqual => first_operand.compare(second_operand).map(|ordering| ordering != Ordering::Greater),
+            Operator::Greater => first_operand.compare(second_operand).map(|ordering| ordering == Ordering::Greater),
+            Operator::GreaterEqual => first_operand.compare(second_operand).map(|ordering| ordering != Ordering::Less),
+            _ => Err(ArithmeticError::NotRepresentable)
+        }
+    }
+
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            *self,
+            Operator::Less | Operator::LessEqual | Operator::Greater | Operator::GreaterEqual | Operator::Equal
+                | Operator::NotEqual | Operator::ApproximatelyEqual
+        )
+    }
+
+    // Whether `next`, following `self` without parentheses, continues a
+    // chain of comparisons such as `0 <= x < 10`. Only comparisons of the
+    // same precedence chain: `a < b == c` still compares `a < b` to `c`.
+    pub fn chains_with(&self, next: &Operator) -> bool {
+        self.is_comparison() && next.is_comparison() && self.precedence() == next.precedence()
+    }
+
+    // Whether the operator combines booleans, the interpreter evaluating the
+    // second operand only when the first one does not decide the result.
+    pub fn is_logical(&self) -> bool {
+        *self == Operator::And || *self == Operator::Or
+    }
+
     pub fn new(c: char) -> Option<Operator> {
         match c {
             '+' => Some(Operator::Plus),
@@ -26,32 +107,101 @@ impl Operator {
             '*' => Some(Operator::Times),
             '/' => Some(Operator::Slash),
             '^' => Some(Operator::Caret),
+            '%' => Some(Operator::Percent),
+            '<' => Some(Operator::Less),
+            '>' => Some(Operator::Greater),
+            '±' => Some(Operator::PlusMinus),
+            '≈' => Some(Operator::ApproximatelyEqual),
+            '&' => Some(Operator::BitAnd),
+            '|' => Some(Operator::BitOr),
             _ => None
         }
     }
 
-    pub fn to_char(&self) -> char {
+    // Multi-character operator starting `text`, with its length in
+    // characters.
+    pub fn from_prefix(text: &str) -> Option<(Operator, usize)> {
+        MULTI_CHARACTER_OPERATORS
+            .iter()
+            .find(|&&(symbol, _)| text.starts_with(symbol))
+            .map(|(symbol, operator)| (operator.clone(), symbol.chars().count()))
+    }
+
+    // Operator spelled as a word, which is then not an identifier.
+    pub fn from_word(word: &str) -> Option<Operator> {
+        match word {
+            "xor" => Some(Operator::Xor),
+            _ => None
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
         match *self {
-            Operator::Plus => '+',
-            Operator::Minus => '-',
-            Operator::Times => '*',
-            Operator::Slash => '/',
-            Operator::Caret => '^'
+            Operator::Plus => "+",
+            Operator::Minus => "-",
+            Operator::PlusMinus => "±",
+            Operator::Times => "*",
+            Operator::Slash => "/",
+            Operator::DoubleSlash => "//",
+            Operator::Percent => "%",
+            Operator::Caret => "^",
+            Operator::Less => "<",
+            Operator::LessEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterEqual => ">=",
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::ApproximatelyEqual => "≈",
+            Operator::And => "&&",
+            Operator::Or => "||",
+            Operator::BitAnd => "&",
+            Operator::BitOr => "|",
+            Operator::Xor => "xor",
+            Operator::ShiftLeft => "<<",
+            Operator::ShiftRight => ">>"
         }
     }
 
-    fn priority(&self) -> u32 {
+    // Conditionals bind looser than any operator, with a precedence of 1.
+    // Bitwise operators and shifts are ranked as in C: `a & b == c` is
+    // `a & (b == c)` and `1 << n - 1` is `1 << (n - 1)`.
+    pub fn precedence(&self) -> u32 {
         match *self {
-            Operator::Plus | Operator::Minus => 1,
-            Operator::Times | Operator::Slash => 2,
-            Operator::Caret => 3
+            Operator::Or => 2,
+            Operator::And => 3,
+            Operator::BitOr => 4,
+            Operator::Xor => 5,
+            Operator::BitAnd => 6,
+            Operator::Equal | Operator::NotEqual | Operator::ApproximatelyEqual => 7,
+            Operator::Less | Operator::LessEqual | Operator::Greater | Operator::GreaterEqual => 8,
+            Operator::ShiftLeft | Operator::ShiftRight => 9,
+            Operator::Plus | Operator::Minus => 10,
+            Operator::PlusMinus => 11,
+            Operator::Times | Operator::Slash | Operator::DoubleSlash | Operator::Percent => 12,
+            Operator::Caret => 14
+        }
+    }
+
+    pub fn associativity(&self) -> Associativity {
+        match *self {
+            Operator::Caret => Associativity::Right,
+            _ => Associativity::Left
+        }
+    }
+
+    // Whether `self`, already on the parser's stack, has to be applied before
+    // `next` is pushed.
+    pub fn takes_precedence_over(&self, next: &Operator) -> bool {
+        match next.associativity() {
+            Associativity::Left => self.precedence() >= next.precedence(),
+            Associativity::Right => self.precedence() > next.precedence()
         }
     }
 }
 
 impl Display for Operator {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "{}", self.to_char())
+        write!(f, "{}", self.symbol())
     }
 }
 
@@ -60,25 +210,28 @@ impl Debug for Operator {
         let enum_name: &str = match *self {
             Operator::Plus => "Operator::Plus",
             Operator::Minus => "Operator::Minus",
+            Operator::PlusMinus => "Operator::PlusMinus",
             Operator::Times => "Operator::Times",
             Operator::Slash => "Operator::Slash",
-            Operator::Caret => "Operator::Caret"
+            Operator::DoubleSlash => "Operator::DoubleSlash",
+            Operator::Percent => "Operator::Percent",
+            Operator::Caret => "Operator::Caret",
+            Operator::Less => "Operator::Less",
+            Operator::LessEqual => "Operator::LessEqual",
+            Operator::Greater => "Operator::Greater",
+            Operator::GreaterEqual => "Operator::GreaterEqual",
+            Operator::Equal => "Operator::Equal",
+            Operator::NotEqual => "Operator::NotEqual",
+            Operator::ApproximatelyEqual => "Operator::ApproximatelyEqual",
+            Operator::And => "Operator::And",
+            Operator::Or => "Operator::Or",
+            Operator::BitAnd => "Operator::BitAnd",
+            Operator::BitOr => "Operator::BitOr",
+            Operator::Xor => "Operator::Xor",
+            Operator::ShiftLeft => "Operator::ShiftLeft",
+            Operator::ShiftRight => "Operator::ShiftRight"
         };
-        write!(f, "{} ({})", enum_name, self.to_char())
-    }
-}
-
-impl PartialEq for Operator {
-    fn eq(&self, other: &Operator) -> bool {
-        self.to_char() == other.to_char()
-    }
-}
-
-impl PartialOrd for Operator {
-    fn partial_cmp(&self, other: &Operator) -> Option<Ordering> {
-        Some(
-            self.priority().cmp(&other.priority())
-        )
+        write!(f, "{} ({})", enum_name, self.symbol())
     }
 }
 
@@ -89,33 +242,37 @@ mod tests {
     #[test]
     fn each_operator_has_a_character_representation() {
         let result: String = format!(
-            "{}{}{}{}{}",
+            "{}{}{}{}{}{}{}{}",
             Operator::Plus,
             Operator::Minus,
+            Operator::PlusMinus,
             Operator::Times,
             Operator::Slash,
+            Operator::DoubleSlash,
+            Operator::Percent,
             Operator::Caret
         );
-        let expected_result: &str = "+-*/^";
+        let expected_result: &str = "+-±*///%^";
 
         assert_eq!(result, expected_result);
     }
 
     #[test]
     fn operators_can_be_instanciated_from_characters() {
-        let result: Vec<Operator> = ['+', '-', '*', '/', '^']
+        let result: Vec<Operator> = ['+', '-', '±', '*', '/', '%', '^', '≈']
             .iter()
-            .map(|c| Operator::new(*c))
-            .filter(|c| c.is_some())
-            .map(|c| c.unwrap())
+            .filter_map(|c| Operator::new(*c))
             .collect();
 
-        let expected_result: [Operator; 5] = [
+        let expected_result: [Operator; 8] = [
             Operator::Plus,
             Operator::Minus,
+            Operator::PlusMinus,
             Operator::Times,
             Operator::Slash,
-            Operator::Caret
+            Operator::Percent,
+            Operator::Caret,
+            Operator::ApproximatelyEqual
         ];
 
         assert_eq!(result, expected_result);
@@ -125,48 +282,192 @@ mod tests {
     fn operator_constructor_return_none_if_character_is_not_an_operator() {
         let result: Vec<Operator> = ['a', '1', ',']
             .iter()
-            .map(|c| Operator::new(*c))
-            .filter(|c| c.is_some())
-            .map(|c| c.unwrap())
+            .filter_map(|c| Operator::new(*c))
             .collect();
 
-        assert_eq!(result.is_empty(), true);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn multi_character_operators_are_recognized_at_the_start_of_a_string() {
+        let result: Vec<Option<(Operator, usize)>> = ["+/- 0.1", "+ 1", "+/", "// 2", "/ 2", "<= 1", "!= 1", "~= 1", "&&", "|", "<< 2", "< 2"]
+            .iter()
+            .map(|string| Operator::from_prefix(string))
+            .collect();
+
+        let expected_result: Vec<Option<(Operator, usize)>> = [
+            Some((Operator::PlusMinus, 3)),
+            None,
+            None,
+            Some((Operator::DoubleSlash, 2)),
+            None,
+            Some((Operator::LessEqual, 2)),
+            Some((Operator::NotEqual, 2)),
+            Some((Operator::ApproximatelyEqual, 2)),
+            Some((Operator::And, 2)),
+            None,
+            Some((Operator::ShiftLeft, 2)),
+            None
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
     }
 
     #[test]
     fn operators_can_be_ordered_following_precedence_in_arithmetic() {
         let result: Vec<bool> = [
-            (Operator::Caret > Operator::Times),
-            (Operator::Caret > Operator::Slash),
-            (Operator::Times > Operator::Plus),
-            (Operator::Times > Operator::Minus),
-            (Operator::Slash > Operator::Plus),
-            (Operator::Slash > Operator::Minus),
-
-            (Operator::Times < Operator::Caret),
-            (Operator::Slash < Operator::Caret),
-            (Operator::Plus < Operator::Times),
-            (Operator::Minus < Operator::Times),
-            (Operator::Plus < Operator::Slash),
-            (Operator::Minus < Operator::Slash),
+            (Operator::Caret.precedence() > Operator::Times.precedence()),
+            (Operator::Caret.precedence() > Operator::Slash.precedence()),
+            (Operator::Times.precedence() > Operator::Plus.precedence()),
+            (Operator::Times.precedence() > Operator::Minus.precedence()),
+            (Operator::Slash.precedence() > Operator::Plus.precedence()),
+            (Operator::Slash.precedence() > Operator::Minus.precedence()),
+            (Operator::PlusMinus.precedence() > Operator::Plus.precedence()),
+            (Operator::Times.precedence() > Operator::PlusMinus.precedence()),
+
+            (Operator::Times.precedence() == Operator::Slash.precedence()),
+            (Operator::Slash.precedence() == Operator::DoubleSlash.precedence()),
+            (Operator::Slash.precedence() == Operator::Percent.precedence()),
+            (Operator::Plus.precedence() == Operator::Minus.precedence()),
 
             (Operator::Times == Operator::Slash),
-            (Operator::Plus == Operator::Minus),
-            (Operator::Times > Operator::Slash),
-            (Operator::Slash < Operator::Times),
-            (Operator::Plus > Operator::Minus),
-            (Operator::Minus < Operator::Plus)
+            (Operator::Plus == Operator::Minus)
         ].to_vec();
 
-        let expected_result: [bool; 18] = [
-            true, true, true, true, true, true,
-            true, true, true, true, true, true,
-            false, false, false, false, false, false
+        let expected_result: [bool; 14] = [
+            true, true, true, true, true, true, true, true,
+            true, true, true, true,
+            false, false
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn only_the_exponentiation_is_right_associative() {
+        let result: Vec<Associativity> = [
+            Operator::Plus,
+            Operator::Minus,
+            Operator::Times,
+            Operator::Slash,
+            Operator::Caret
+        ]
+            .iter()
+            .map(|operator| operator.associativity())
+            .collect();
+
+        let expected_result: [Associativity; 5] = [
+            Associativity::Left,
+            Associativity::Left,
+            Associativity::Left,
+            Associativity::Left,
+            Associativity::Right
         ];
 
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn logical_operators_bind_looser_than_comparisons_which_bind_looser_than_arithmetic() {
+        let result: Vec<bool> = [
+            Operator::Or.precedence() < Operator::And.precedence(),
+            Operator::And.precedence() < Operator::Equal.precedence(),
+            Operator::Equal.precedence() < Operator::Less.precedence(),
+            Operator::Less.precedence() < Operator::Plus.precedence(),
+            Operator::Less.precedence() == Operator::GreaterEqual.precedence(),
+            Operator::Equal.precedence() == Operator::NotEqual.precedence(),
+            Operator::Equal.precedence() == Operator::ApproximatelyEqual.precedence()
+        ].to_vec();
+
+        let expected_result: [bool; 7] = [true, true, true, true, true, true, true];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn bitwise_operators_are_ranked_as_in_c() {
+        let result: Vec<bool> = [
+            Operator::And.precedence() < Operator::BitOr.precedence(),
+            Operator::BitOr.precedence() < Operator::Xor.precedence(),
+            Operator::Xor.precedence() < Operator::BitAnd.precedence(),
+            Operator::BitAnd.precedence() < Operator::Equal.precedence(),
+            Operator::Less.precedence() < Operator::ShiftLeft.precedence(),
+            Operator::ShiftRight.precedence() < Operator::Plus.precedence()
+        ].to_vec();
+
+        let expected_result: [bool; 6] = [true, true, true, true, true, true];
+
+        assert_eq!(result, expected_result);
+        assert_eq!(Operator::from_word("xor"), Some(Operator::Xor));
+        assert_eq!(Operator::from_word("x"), None);
+        assert_eq!(Operator::BitAnd.call(12, 10), Ok(8));
+    }
+
+    #[test]
+    fn comparisons_of_the_same_precedence_chain() {
+        let result: Vec<bool> = [
+            Operator::LessEqual.chains_with(&Operator::Less),
+            Operator::Equal.chains_with(&Operator::ApproximatelyEqual),
+            Operator::Less.chains_with(&Operator::Equal),
+            Operator::Plus.chains_with(&Operator::Minus)
+        ].to_vec();
+
+        let expected_result: [bool; 4] = [true, true, false, false];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn approximate_equality_allows_for_the_tolerance() {
+        let tolerance: Tolerance = Tolerance { relative: 1e-6, absolute: 1e-9 };
+        let result: Vec<Result<bool, ArithmeticError>> = [(1.0, 1.0 + 1e-7), (1.0, 1.0 + 1e-5), (1e6, 1e6 + 0.5), (0.0, 1e-10), (0.0, 1e-8)]
+            .iter()
+            .map(|&(first, second): &(f64, f64)| Operator::ApproximatelyEqual.compare(&first, &second, &tolerance, &()))
+            .collect();
+
+        let expected_result: Vec<Result<bool, ArithmeticError>> = [Ok(true), Ok(false), Ok(true), Ok(true), Ok(false)].to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn comparison_operators_compare_their_operands() {
+        let result: Vec<Result<bool, ArithmeticError>> = [
+            Operator::Less,
+            Operator::LessEqual,
+            Operator::Greater,
+            Operator::GreaterEqual,
+            Operator::Equal,
+            Operator::NotEqual,
+            Operator::ApproximatelyEqual
+        ]
+            .iter()
+            .map(|operator| operator.compare(&1.0, &2.0, &Tolerance::default(), &()))
+            .collect();
+
+        let expected_result: Vec<Result<bool, ArithmeticError>> = [
+            Ok(true), Ok(true), Ok(false), Ok(false), Ok(false), Ok(true), Ok(false)
+        ].to_vec();
+
+        assert_eq!(result, expected_result);
+        assert_eq!(Operator::Less.compare(&1.0, &f64::NAN, &Tolerance::default(), &()), Err(ArithmeticError::Incomparable));
+        assert_eq!(Operator::Less.call(1.0, 2.0), Err(ArithmeticError::NotRepresentable));
+    }
+
+    #[test]
+    fn operators_of_equal_precedence_are_grouped_following_associativity() {
+        let result: Vec<bool> = [
+            Operator::Minus.takes_precedence_over(&Operator::Plus),
+            Operator::Slash.takes_precedence_over(&Operator::Slash),
+            Operator::Caret.takes_precedence_over(&Operator::Caret),
+            Operator::Plus.takes_precedence_over(&Operator::Times)
+        ].to_vec();
+
+        let expected_result: [bool; 4] = [true, true, false, false];
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn operator_plus_computes_an_addition() {
         let first_operand: [f32; 2] = [1.0, 2.0];
@@ -175,7 +476,7 @@ mod tests {
         let result: Vec<f32> = first_operand
             .iter()
             .zip(second_operand.iter())
-            .map(|(c1, c2)| Operator::Plus.call(*c1, *c2))
+            .map(|(c1, c2)| Operator::Plus.call(*c1, *c2).unwrap())
             .collect();
         let expected_result: [f32; 2] = [2.0, 3.0];
 
@@ -190,7 +491,7 @@ mod tests {
         let result: Vec<f32> = first_operand
             .iter()
             .zip(second_operand.iter())
-            .map(|(c1, c2)| Operator::Minus.call(*c1, *c2))
+            .map(|(c1, c2)| Operator::Minus.call(*c1, *c2).unwrap())
             .collect();
         let expected_result: [f32; 2] = [0.0, 1.0];
 
@@ -205,7 +506,7 @@ mod tests {
         let result: Vec<f32> = first_operand
             .iter()
             .zip(second_operand.iter())
-            .map(|(c1, c2)| Operator::Times.call(*c1, *c2))
+            .map(|(c1, c2)| Operator::Times.call(*c1, *c2).unwrap())
             .collect();
         let expected_result: [f32; 3] = [1.0, 2.0, 6.0];
 
@@ -220,13 +521,28 @@ mod tests {
         let result: Vec<f32> = first_operand
             .iter()
             .zip(second_operand.iter())
-            .map(|(c1, c2)| Operator::Slash.call(*c1, *c2))
+            .map(|(c1, c2)| Operator::Slash.call(*c1, *c2).unwrap())
             .collect();
         let expected_result: [f32; 3] = [1.0, 0.5, 1.5];
 
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn operator_percent_and_double_slash_round_the_quotient_down() {
+        let first_operand: [i64; 4] = [7, -7, 7, -7];
+        let second_operand: [i64; 4] = [2, 2, -2, -2];
+
+        let result: Vec<(i64, i64)> = first_operand
+            .iter()
+            .zip(second_operand.iter())
+            .map(|(c1, c2)| (Operator::DoubleSlash.call(*c1, *c2).unwrap(), Operator::Percent.call(*c1, *c2).unwrap()))
+            .collect();
+        let expected_result: [(i64, i64); 4] = [(3, 1), (-4, 1), (-4, -1), (3, -1)];
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn operator_caret_computes_a_exponentiation() {
         let first_operand: [f32; 3] = [2.0, 2.0, 2.0];
@@ -235,10 +551,17 @@ mod tests {
         let result: Vec<f32> = first_operand
             .iter()
             .zip(second_operand.iter())
-            .map(|(c1, c2)| Operator::Caret.call(*c1, *c2))
+            .map(|(c1, c2)| Operator::Caret.call(*c1, *c2).unwrap())
             .collect();
         let expected_result: [f32; 3] = [2.0, 4.0, 8.0];
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn operators_report_arithmetic_errors_of_the_number_type() {
+        let result: Result<i64, ArithmeticError> = Operator::Slash.call(1, 0);
+
+        assert_eq!(result, Err(ArithmeticError::DivisionByZero));
+    }
 }
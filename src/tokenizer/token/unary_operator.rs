@@ -0,0 +1,157 @@
+use std::fmt::{Debug, Display, Formatter, Error};
+
+use number::{ArithmeticError, Number};
+use super::operator::Operator;
+
+// Operator with a single operand: the prefix form of `+` and `-`, as in `-3`
+// or `2*-(1+2)`, the logical negation `!`, as in `!(x < 1)`, the bitwise
+// complement `~`, as in `~0xFF`, and the postfix factorial `!`, as in `5!`.
+#[derive(Clone, PartialEq)]
+pub enum UnaryOperator {
+    Plus, Minus, Not, BitNot, Factorial
+}
+
+impl UnaryOperator {
+    // Arithmetic operation of the operator. The logical negation applies to
+    // booleans instead, in the interpreter.
+    pub fn call<N: Number>(&self, operand: N) -> Result<N, ArithmeticError> {
+        match *self {
+            UnaryOperator::Plus => Ok(operand),
+            UnaryOperator::Minus => operand.negate(),
+            UnaryOperator::Not => Err(ArithmeticError::NotRepresentable),
+            UnaryOperator::BitNot => operand.bit_not(),
+            UnaryOperator::Factorial => operand.factorial()
+        }
+    }
+
+    pub fn from_operator(operator: &Operator) -> Option<UnaryOperator> {
+        match *operator {
+            Operator::Plus => Some(UnaryOperator::Plus),
+            Operator::Minus => Some(UnaryOperator::Minus),
+            _ => None
+        }
+    }
+
+    pub fn to_char(&self) -> char {
+        match *self {
+            UnaryOperator::Plus => '+',
+            UnaryOperator::Minus => '-',
+            UnaryOperator::Not | UnaryOperator::Factorial => '!',
+            UnaryOperator::BitNot => '~'
+        }
+    }
+
+    // Whether the operator follows its operand.
+    pub fn is_postfix(&self) -> bool {
+        *self == UnaryOperator::Factorial
+    }
+
+    // Prefix operators bind tighter than `*` and `/` but looser than `^`, so
+    // that `-2^2` is `-(2^2)`. Postfix ones bind tighter than anything, so
+    // that `2^3!` is `2^(3!)`.
+    pub fn precedence(&self) -> u32 {
+        match *self {
+            UnaryOperator::Plus | UnaryOperator::Minus | UnaryOperator::Not | UnaryOperator::BitNot => 13,
+            UnaryOperator::Factorial => 15
+        }
+    }
+
+    // Whether `self`, already on the parser's stack, has to be applied before
+    // `next` is pushed.
+    pub fn takes_precedence_over(&self, next: &Operator) -> bool {
+        self.precedence() >= next.precedence()
+    }
+}
+
+impl Display for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl Debug for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let enum_name: &str = match *self {
+            UnaryOperator::Plus => "UnaryOperator::Plus",
+            UnaryOperator::Minus => "UnaryOperator::Minus",
+            UnaryOperator::Not => "UnaryOperator::Not",
+            UnaryOperator::BitNot => "UnaryOperator::BitNot",
+            UnaryOperator::Factorial => "UnaryOperator::Factorial"
+        };
+        write!(f, "{} ({})", enum_name, self.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_plus_and_minus_have_a_unary_form() {
+        let result: Vec<Option<UnaryOperator>> = [
+            Operator::Plus,
+            Operator::Minus,
+            Operator::Times,
+            Operator::Slash,
+            Operator::Caret
+        ]
+            .iter()
+            .map(UnaryOperator::from_operator)
+            .collect();
+
+        let expected_result: [Option<UnaryOperator>; 5] = [
+            Some(UnaryOperator::Plus),
+            Some(UnaryOperator::Minus),
+            None,
+            None,
+            None
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn unary_operators_bind_between_multiplication_and_exponentiation() {
+        let result: Vec<bool> = [
+            UnaryOperator::Minus.takes_precedence_over(&Operator::Times),
+            UnaryOperator::Minus.takes_precedence_over(&Operator::Plus),
+            UnaryOperator::Minus.takes_precedence_over(&Operator::Caret)
+        ].to_vec();
+
+        let expected_result: [bool; 3] = [true, true, false];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn unary_operators_compute_their_operand_sign() {
+        let result: Vec<Result<f32, ArithmeticError>> = [
+            UnaryOperator::Plus.call(2.0),
+            UnaryOperator::Minus.call(2.0),
+            UnaryOperator::BitNot.call(2.0)
+        ].to_vec();
+
+        let expected_result: [Result<f32, ArithmeticError>; 3] = [Ok(2.0), Ok(-2.0), Ok(-3.0)];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn factorial_is_only_defined_on_non_negative_integers() {
+        let result: Vec<Result<f32, ArithmeticError>> = [
+            UnaryOperator::Factorial.call(0.0),
+            UnaryOperator::Factorial.call(5.0),
+            UnaryOperator::Factorial.call(-1.0),
+            UnaryOperator::Factorial.call(1.5)
+        ].to_vec();
+
+        let expected_result: [Result<f32, ArithmeticError>; 4] = [
+            Ok(1.0),
+            Ok(120.0),
+            Err(ArithmeticError::OutOfDomain),
+            Err(ArithmeticError::OutOfDomain)
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+}